@@ -0,0 +1,49 @@
+//! Compares `convert_slice` against a plain per-element loop.
+//!
+//! Run with `cargo bench --features std`.
+
+use primitive_from::{convert_slice, PrimitiveFromSlice};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const LEN: usize = 4096;
+const ITERATIONS: u32 = 20_000;
+
+fn time(mut f: impl FnMut()) -> Duration {
+    for _ in 0..ITERATIONS / 10 {
+        f();
+    }
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn bench<T: Copy, U: PrimitiveFromSlice<T> + Copy>(name: &str, src: &[T], zero: U) {
+    let mut dst = vec![zero; src.len()];
+    let scalar = time(|| {
+        for (d, &s) in dst.iter_mut().zip(black_box(src)) {
+            *d = U::from(s);
+        }
+        black_box(&mut dst);
+    });
+    let slice = time(|| {
+        convert_slice(black_box(src), &mut dst);
+        black_box(&mut dst);
+    });
+    println!("{:<12} scalar {:>10?}   convert_slice {:>10?}", name, scalar, slice);
+}
+
+fn main() {
+    let ints: Vec<i16> = (0..LEN).map(|i| (i as i16).wrapping_mul(31)).collect();
+    let floats: Vec<f32> = (0..LEN).map(|i| (i as f32 - 2048.0) * 17.3).collect();
+    let bytes: Vec<u8> = (0..LEN).map(|i| i as u8).collect();
+
+    bench("i16 -> f32", &ints, 0f32);
+    bench("f32 -> i16", &floats, 0i16);
+    bench("u8 -> f32", &bytes, 0f32);
+    // No explicit SIMD path, this one relies on auto-vectorisation.
+    let wide: Vec<i32> = ints.iter().map(|&i| i.into()).collect();
+    bench("i32 -> f64", &wide, 0f64);
+}
@@ -0,0 +1,76 @@
+#![no_std]
+
+use primitive_from::audio::{convert_samples_dithered, Dither, I24};
+use primitive_from::endian::{BigEndian, LittleEndian};
+use primitive_from::rounding::NearestTiesEven;
+use primitive_from::{
+    convert_slice, Cast, CheckedPrimitiveFrom, ConversionKind, ExactPrimitiveFrom, LosslessPrimitiveFrom,
+    NormalizedFrom, PrimitiveFrom, PrimitiveInto, ReadPrimitiveFrom, RoundingPrimitiveFrom, SaturatingPrimitiveFrom,
+    TryPrimitiveFrom, TryPrimitiveFromError, WrappingPrimitiveFrom, WritePrimitiveInto,
+};
+
+pub fn primitive_from(a: f64) -> u8 {
+    PrimitiveFrom::from(a)
+}
+
+pub fn primitive_into(a: i16) -> f32 {
+    a.primitive_into()
+}
+
+pub fn checked(a: u32) -> Option<char> {
+    CheckedPrimitiveFrom::checked_from(a)
+}
+
+pub fn saturating(a: f32) -> i16 {
+    SaturatingPrimitiveFrom::saturating_from(a)
+}
+
+pub fn wrapping(a: u128) -> u8 {
+    WrappingPrimitiveFrom::wrapping_from(a)
+}
+
+pub fn try_from(a: f64) -> Result<i32, TryPrimitiveFromError<f64>> {
+    TryPrimitiveFrom::try_from(a)
+}
+
+pub fn exact(a: u64) -> Result<f64, TryPrimitiveFromError<u64>> {
+    ExactPrimitiveFrom::exact_from(a)
+}
+
+pub fn rounding(a: f32) -> i32 {
+    <i32 as RoundingPrimitiveFrom<f32, NearestTiesEven>>::rounding_from(a)
+}
+
+pub fn normalized(a: f32) -> u8 {
+    NormalizedFrom::normalized_from(a)
+}
+
+pub const CAST: f32 = Cast::<u8, f32>::cast(255);
+
+pub fn slice(src: &[i16], dst: &mut [f32]) {
+    convert_slice(src, dst)
+}
+
+pub fn read(bytes: [u8; 2]) -> i64 {
+    <i64 as ReadPrimitiveFrom<u16, BigEndian>>::read_from(bytes)
+}
+
+pub fn write(a: i64) -> Option<[u8; 4]> {
+    <i64 as WritePrimitiveInto<u32, LittleEndian>>::write_into(a)
+}
+
+pub fn audio(src: &[u8], dst: &mut [i16], seed: u64) {
+    convert_samples_dithered(I24::from_packed(src), dst, &mut Dither::new(seed))
+}
+
+pub const KIND: bool = <f64 as ConversionKind<i32>>::LOSSLESS;
+
+pub fn lossless<T, U: LosslessPrimitiveFrom<T>>(a: T) -> U {
+    U::from(a)
+}
+
+// A second panic handler is an error (E0152) if std is linked in.
+#[panic_handler]
+fn panic(_: &core::panic::PanicInfo) -> ! {
+    loop {}
+}
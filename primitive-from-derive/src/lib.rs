@@ -0,0 +1,276 @@
+//! Derive macro for `primitive_from::PrimitiveFrom`. This crate is
+//! re-exported by `primitive-from` behind its `derive` feature, so it does
+//! not need to be depended on directly.
+
+extern crate proc_macro;
+
+use proc_macro::{Delimiter, TokenStream, TokenTree};
+use std::fmt::Write;
+
+const INTEGERS: &[&str] = &["u8", "i8", "u16", "i16", "u32", "i32", "u64", "isize", "usize", "i64", "u128", "i128"];
+const FLOATS: &[&str] = &["f32", "f64"];
+
+/// The types a primitive can be cast to with `PrimitiveFrom`. This mirrors
+/// the rows of `impl_primitive_from!` in `primitive-from`.
+fn targets(source: &str) -> Vec<&'static str> {
+    let numbers = INTEGERS.iter().chain(FLOATS).copied();
+    match source {
+        "u8" => Some("char").into_iter().chain(numbers).collect(),
+        "char" => Some("char").into_iter().chain(numbers).collect(),
+        "bool" => Some("bool").into_iter().chain(numbers).collect(),
+        _ if INTEGERS.contains(&source) || FLOATS.contains(&source) => numbers.collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// The types a primitive can be cast from with `PrimitiveFrom`.
+fn sources(target: &str) -> Vec<&'static str> {
+    INTEGERS
+        .iter()
+        .chain(FLOATS)
+        .chain(&["char", "bool"])
+        .copied()
+        .filter(|source| targets(source).contains(&target))
+        .collect()
+}
+
+struct Newtype {
+    name: String,
+    field: String,
+    tuple: bool,
+    inner: String,
+    from: Option<Vec<String>>,
+    into: Option<Vec<String>>,
+}
+
+/// Derives `PrimitiveFrom` for a struct with a single primitive field, by
+/// going through the inner field in both directions.
+///
+/// For `struct Meters(f32)` this generates `PrimitiveFrom<T> for Meters`
+/// for every `T` that `f32` can be cast from, and `PrimitiveFrom<Meters>
+/// for U` for every `U` that `f32` can be cast to. Like every primitive,
+/// `Meters` can also be cast from itself.
+///
+/// The generated types can be limited with
+/// `#[primitive_from(from(..), into(..))]`. Either list can be left out to
+/// keep the default. The lists are required when the field is not a
+/// primitive, for example another newtype.
+///
+/// # Examples
+///
+/// ```
+/// use primitive_from::PrimitiveFrom;
+///
+/// #[derive(PrimitiveFrom, Debug, PartialEq)]
+/// struct Meters(f32);
+///
+/// let m: Meters = PrimitiveFrom::from(3u8);
+/// assert_eq!(m, Meters(3.0));
+/// let x: i64 = PrimitiveFrom::from(Meters(-2.5));
+/// assert_eq!(x, -2);
+///
+/// #[derive(PrimitiveFrom, Debug, PartialEq)]
+/// #[primitive_from(from(u8, u16), into(u32))]
+/// struct Level {
+///     value: u16,
+/// }
+///
+/// let l: Level = PrimitiveFrom::from(7u8);
+/// assert_eq!(l, Level { value: 7 });
+/// let x: u32 = PrimitiveFrom::from(l);
+/// assert_eq!(x, 7);
+/// ```
+#[proc_macro_derive(PrimitiveFrom, attributes(primitive_from))]
+pub fn derive_primitive_from(input: TokenStream) -> TokenStream {
+    let code = parse(input).and_then(|newtype| expand(&newtype));
+    match code {
+        Ok(code) => code.parse().unwrap(),
+        Err(message) => format!("compile_error!({:?});", message).parse().unwrap(),
+    }
+}
+
+fn parse(input: TokenStream) -> Result<Newtype, String> {
+    let mut tokens = input.into_iter().peekable();
+    let mut from = None;
+    let mut into = None;
+
+    loop {
+        match tokens.next() {
+            Some(TokenTree::Punct(p)) if p.as_char() == '#' => {
+                if let Some(TokenTree::Group(g)) = tokens.next() {
+                    parse_attribute(g.stream(), &mut from, &mut into)?;
+                }
+            }
+            Some(TokenTree::Ident(i)) if i.to_string() == "pub" => {
+                if let Some(TokenTree::Group(g)) = tokens.peek() {
+                    if g.delimiter() == Delimiter::Parenthesis {
+                        tokens.next();
+                    }
+                }
+            }
+            Some(TokenTree::Ident(i)) if i.to_string() == "struct" => break,
+            _ => return Err("PrimitiveFrom can only be derived for structs".to_string()),
+        }
+    }
+
+    let name = match tokens.next() {
+        Some(TokenTree::Ident(i)) => i.to_string(),
+        _ => return Err("expected a struct name".to_string()),
+    };
+
+    let body = match tokens.next() {
+        Some(TokenTree::Group(g)) if g.delimiter() != Delimiter::Bracket => g,
+        Some(TokenTree::Punct(p)) if p.as_char() == '<' => {
+            return Err("PrimitiveFrom cannot be derived for generic structs".to_string())
+        }
+        _ => return Err("PrimitiveFrom can only be derived for structs with a single field".to_string()),
+    };
+    let tuple = body.delimiter() == Delimiter::Parenthesis;
+
+    let mut fields = split_commas(body.stream());
+    if fields.len() != 1 {
+        return Err("PrimitiveFrom can only be derived for structs with a single field".to_string());
+    }
+    let mut field = fields.remove(0).into_iter().peekable();
+
+    // Skip the field's attributes and visibility.
+    loop {
+        match field.peek() {
+            Some(TokenTree::Punct(p)) if p.as_char() == '#' => {
+                field.next();
+                field.next();
+            }
+            Some(TokenTree::Ident(i)) if i.to_string() == "pub" => {
+                field.next();
+                if let Some(TokenTree::Group(g)) = field.peek() {
+                    if g.delimiter() == Delimiter::Parenthesis {
+                        field.next();
+                    }
+                }
+            }
+            _ => break,
+        }
+    }
+
+    let field_name = if tuple {
+        "0".to_string()
+    } else {
+        let name = field.next().map(|t| t.to_string()).unwrap_or_default();
+        field.next(); // `:`
+        name
+    };
+    let inner = field.collect::<TokenStream>().to_string();
+
+    Ok(Newtype { name, field: field_name, tuple, inner, from, into })
+}
+
+fn parse_attribute(
+    attribute: TokenStream,
+    from: &mut Option<Vec<String>>,
+    into: &mut Option<Vec<String>>,
+) -> Result<(), String> {
+    let mut tokens = attribute.into_iter();
+    match tokens.next() {
+        Some(TokenTree::Ident(i)) if i.to_string() == "primitive_from" => {}
+        _ => return Ok(()),
+    }
+    let args = match tokens.next() {
+        Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Parenthesis => g.stream(),
+        _ => return Err("expected #[primitive_from(from(..), into(..))]".to_string()),
+    };
+
+    for arg in split_commas(args) {
+        let mut arg = arg.into_iter();
+        let (key, list) = match (arg.next(), arg.next()) {
+            (Some(TokenTree::Ident(key)), Some(TokenTree::Group(list)))
+                if list.delimiter() == Delimiter::Parenthesis =>
+            {
+                (key.to_string(), list.stream())
+            }
+            _ => return Err("expected #[primitive_from(from(..), into(..))]".to_string()),
+        };
+        let types = split_commas(list)
+            .into_iter()
+            .map(|ty| ty.into_iter().collect::<TokenStream>().to_string())
+            .collect();
+        match key.as_str() {
+            "from" => *from = Some(types),
+            "into" => *into = Some(types),
+            _ => return Err(format!("unknown primitive_from option `{}`, expected `from` or `into`", key)),
+        }
+    }
+    Ok(())
+}
+
+/// Splits a token stream on its top level commas, dropping empty pieces so
+/// trailing commas are allowed.
+fn split_commas(stream: TokenStream) -> Vec<Vec<TokenTree>> {
+    let mut pieces = vec![Vec::new()];
+    let mut depth = 0;
+    for token in stream {
+        match &token {
+            // Commas inside generic arguments don't separate anything.
+            TokenTree::Punct(p) if p.as_char() == '<' => depth += 1,
+            TokenTree::Punct(p) if p.as_char() == '>' => depth -= 1,
+            TokenTree::Punct(p) if p.as_char() == ',' && depth == 0 => {
+                pieces.push(Vec::new());
+                continue;
+            }
+            _ => {}
+        }
+        pieces.last_mut().unwrap().push(token);
+    }
+    pieces.retain(|piece| !piece.is_empty());
+    pieces
+}
+
+fn expand(newtype: &Newtype) -> Result<String, String> {
+    let Newtype { name, field, tuple, inner, from, into } = newtype;
+    let from = from.clone().unwrap_or_else(|| sources(inner).iter().map(|s| s.to_string()).collect());
+    let into = into.clone().unwrap_or_else(|| targets(inner).iter().map(|s| s.to_string()).collect());
+    if from.is_empty() && into.is_empty() {
+        return Err(format!(
+            "`{}` is not a primitive, so the types to convert from and into have to be listed \
+             with #[primitive_from(from(..), into(..))]",
+            inner
+        ));
+    }
+
+    // Like every primitive, the newtype can be cast from itself.
+    let mut code = format!(
+        "impl ::primitive_from::PrimitiveFrom<{name}> for {name} {{\
+            #[inline] fn from(a: {name}) -> {name} {{ a }}\
+        }}",
+        name = name,
+    );
+    for source in &from {
+        let value = format!("<{} as ::primitive_from::PrimitiveFrom<{}>>::from(a)", inner, source);
+        let value = if *tuple { format!("{}({})", name, value) } else { format!("{} {{ {}: {} }}", name, field, value) };
+        write!(
+            code,
+            "impl ::primitive_from::PrimitiveFrom<{source}> for {name} {{\
+                #[inline] fn from(a: {source}) -> {name} {{ {value} }}\
+            }}",
+            source = source,
+            name = name,
+            value = value,
+        )
+        .unwrap();
+    }
+    for target in &into {
+        write!(
+            code,
+            "impl ::primitive_from::PrimitiveFrom<{name}> for {target} {{\
+                #[inline] fn from(a: {name}) -> {target} {{\
+                    <{target} as ::primitive_from::PrimitiveFrom<{inner}>>::from(a.{field})\
+                }}\
+            }}",
+            name = name,
+            target = target,
+            inner = inner,
+            field = field,
+        )
+        .unwrap();
+    }
+    Ok(code)
+}
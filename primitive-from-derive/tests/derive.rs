@@ -0,0 +1,80 @@
+use primitive_from::{PrimitiveFrom, PrimitiveInto};
+
+#[derive(PrimitiveFrom, Debug, PartialEq)]
+struct Meters(f32);
+
+#[derive(PrimitiveFrom, Debug, PartialEq)]
+pub struct Index {
+    pub value: usize,
+}
+
+#[derive(PrimitiveFrom, Debug, PartialEq)]
+#[primitive_from(from(u8, i16))]
+struct Sample(pub(crate) i32);
+
+#[derive(PrimitiveFrom, Debug, PartialEq)]
+#[primitive_from(from(Meters), into(Meters, f64))]
+struct Distance(Meters);
+
+#[derive(PrimitiveFrom, Debug, PartialEq)]
+struct Letter(char);
+
+#[test]
+fn derive_tuple_struct() {
+    let m: Meters = PrimitiveFrom::from(-7i8);
+    assert_eq!(m, Meters(-7.0));
+    let m: Meters = 2.5f64.primitive_into();
+    assert_eq!(m, Meters(2.5));
+    let m: Meters = PrimitiveFrom::from(u128::MAX);
+    assert_eq!(m, Meters(f32::INFINITY));
+
+    let x: u8 = PrimitiveFrom::from(Meters(300.7));
+    assert_eq!(x, 255);
+    let x: f64 = Meters(0.5).primitive_into();
+    assert_eq!(x, 0.5);
+}
+
+#[test]
+fn derive_named_struct() {
+    let i: Index = PrimitiveFrom::from(true);
+    assert_eq!(i, Index { value: 1 });
+    let i: Index = PrimitiveFrom::from('A');
+    assert_eq!(i, Index { value: 65 });
+    let x: i8 = PrimitiveFrom::from(Index { value: 200 });
+    assert_eq!(x, -56);
+}
+
+#[test]
+fn derive_limited_sources() {
+    let s: Sample = PrimitiveFrom::from(-3i16);
+    assert_eq!(s, Sample(-3));
+    let x: u16 = PrimitiveFrom::from(Sample(-1));
+    assert_eq!(x, u16::MAX);
+}
+
+#[test]
+fn derive_nested_newtype() {
+    let d: Distance = PrimitiveFrom::from(Meters(4.0));
+    assert_eq!(d, Distance(Meters(4.0)));
+    let x: f64 = PrimitiveFrom::from(Distance(Meters(1.5)));
+    assert_eq!(x, 1.5);
+}
+
+#[test]
+fn derive_char() {
+    let l: Letter = PrimitiveFrom::from(0x41u8);
+    assert_eq!(l, Letter('A'));
+    let x: u32 = PrimitiveFrom::from(Letter('\u{1F600}'));
+    assert_eq!(x, 0x1F600);
+}
+
+#[derive(PrimitiveFrom, Debug, PartialEq)]
+struct Flag(bool);
+
+#[test]
+fn derive_bool() {
+    let f: Flag = PrimitiveFrom::from(true);
+    assert_eq!(f, Flag(true));
+    let x: f32 = PrimitiveFrom::from(Flag(true));
+    assert_eq!(x, 1.0);
+}
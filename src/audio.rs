@@ -0,0 +1,293 @@
+//! Conversions between audio sample formats.
+//!
+//! Samples are mapped by full scale instead of by value: `i16::MIN`, the
+//! 24-bit minimum, `i32::MIN` and `-1.0f32` all mean the same level. Integer
+//! formats are asymmetric, so full scale is `2^(bits - 1)`: the integer
+//! minimum maps exactly to `-1.0`, and `1.0` clamps to the integer maximum.
+//!
+//! [`convert_samples`] rounds to the nearest step of the target, and
+//! [`convert_samples_dithered`] adds TPDF dither first, to decorrelate the
+//! rounding error from the signal when reducing the bit depth. Both work on
+//! whole buffers, so interleaved frames can be passed as they are, and every
+//! channel gets independent dither.
+//!
+//! # Examples
+//!
+//! ```
+//! use primitive_from::audio::{convert_samples, convert_samples_dithered, Dither, I24};
+//!
+//! let pcm = [i16::MIN, -16384, 0, 16384, i16::MAX];
+//! let mut float = [0f32; 5];
+//! convert_samples(&pcm, &mut float);
+//! assert_eq!(float[..4], [-1.0, -0.5, 0.0, 0.5]);
+//!
+//! let mut wide = [I24::default(); 5];
+//! convert_samples(&pcm, &mut wide);
+//! assert_eq!(wide[0].get(), -8388608);
+//! assert_eq!(wide[4].get(), 32767 << 8);
+//!
+//! // Stereo frames from a 24-bit packed buffer, down to 16 bits.
+//! let bytes = [0x00, 0x00, 0x80, 0xFF, 0xFF, 0x7F];
+//! let mut out = [0i16; 2];
+//! convert_samples_dithered(I24::from_packed(&bytes), &mut out, &mut Dither::new(7));
+//! assert_eq!(out, [i16::MIN, i16::MAX]);
+//! ```
+
+use crate::rounding::NearestTiesEven;
+use crate::{PrimitiveFrom, RoundingPrimitiveFrom};
+
+/// A sample format that [`convert_samples`] can read and write.
+pub trait Sample: Copy
+{
+    /// The size of one step of the format, relative to full scale. It is 0
+    /// for float formats, which are not dithered.
+    const STEP: f64;
+
+    /// The sample relative to full scale, in `-1.0..1.0` for integers.
+    fn to_full_scale(self) -> f64;
+
+    /// A sample from a value relative to full scale, rounded to the nearest
+    /// step. Integers clamp out of range values, and NaN maps to 0.
+    fn from_full_scale(_:f64) -> Self;
+}
+
+macro_rules! impl_sample {
+    (int $( $T: ty ),* ) => {
+        $(
+        impl Sample for $T {
+            const STEP: f64 = 1.0 / (1u64 << (<$T>::BITS - 1)) as f64;
+
+            #[inline] fn to_full_scale(self) -> f64 { <f64 as PrimitiveFrom<$T>>::from(self) * Self::STEP }
+
+            #[inline] fn from_full_scale(a:f64) -> $T {
+                // Rounding saturates out of range values.
+                <$T as RoundingPrimitiveFrom<f64, NearestTiesEven>>::rounding_from(a / Self::STEP)
+            }
+        }
+        )*
+    };
+}
+
+impl_sample!(int i16, i32);
+
+impl Sample for f32 {
+    const STEP: f64 = 0.0;
+
+    #[inline] fn to_full_scale(self) -> f64 { PrimitiveFrom::from(self) }
+
+    #[inline] fn from_full_scale(a:f64) -> f32 { PrimitiveFrom::from(a) }
+}
+
+/// A signed 24-bit sample, packed into 3 little-endian bytes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct I24(pub [u8; 3]);
+
+impl I24 {
+    /// The smallest value, -2^23.
+    pub const MIN: i32 = -(1 << 23);
+    /// The largest value, 2^23 - 1.
+    pub const MAX: i32 = (1 << 23) - 1;
+
+    /// A sample from the low 24 bits of `a`, like `as` would keep them.
+    #[inline]
+    pub fn new(a: i32) -> I24 {
+        let [b0, b1, b2, _] = a.to_le_bytes();
+        I24([b0, b1, b2])
+    }
+
+    /// The value of the sample, in `I24::MIN..=I24::MAX`.
+    #[inline]
+    pub fn get(self) -> i32 {
+        let [b0, b1, b2] = self.0;
+        i32::from_le_bytes([0, b0, b1, b2]) >> 8
+    }
+
+    /// Views a buffer of packed 24-bit samples as samples.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `bytes` is not a multiple of 3.
+    pub fn from_packed(bytes: &[u8]) -> &[I24] {
+        assert_eq!(bytes.len() % 3, 0, "packed 24-bit samples need a multiple of 3 bytes");
+        // `I24` is a transparent wrapper around `[u8; 3]`, so it has the
+        // same size and an alignment of 1.
+        unsafe { core::slice::from_raw_parts(bytes.as_ptr() as *const I24, bytes.len() / 3) }
+    }
+
+    /// Views a buffer of packed 24-bit samples as mutable samples.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `bytes` is not a multiple of 3.
+    pub fn from_packed_mut(bytes: &mut [u8]) -> &mut [I24] {
+        assert_eq!(bytes.len() % 3, 0, "packed 24-bit samples need a multiple of 3 bytes");
+        unsafe { core::slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut I24, bytes.len() / 3) }
+    }
+}
+
+impl Sample for I24 {
+    const STEP: f64 = 1.0 / (1 << 23) as f64;
+
+    #[inline] fn to_full_scale(self) -> f64 { <f64 as PrimitiveFrom<i32>>::from(self.get()) * Self::STEP }
+
+    #[inline] fn from_full_scale(a:f64) -> I24 {
+        let b = <i32 as RoundingPrimitiveFrom<f64, NearestTiesEven>>::rounding_from(a / Self::STEP);
+        I24::new(b.clamp(I24::MIN, I24::MAX))
+    }
+}
+
+/// A seedable source of TPDF (triangular) dither, for
+/// [`convert_samples_dithered`].
+///
+/// The noise is the sum of two uniform values, spanning one step of the
+/// target format either side of the sample. The same seed always gives the
+/// same output.
+#[derive(Debug, Clone)]
+pub struct Dither {
+    state: u64,
+}
+
+impl Dither {
+    /// Creates a generator from a seed. Any seed, including 0, works.
+    pub fn new(seed: u64) -> Dither {
+        Dither { state: seed }
+    }
+
+    // SplitMix64, which is small and has no bad seeds.
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A uniform value in `0.0..1.0`.
+    #[inline]
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// Triangular noise in `-1.0..1.0`, in steps of the target.
+    #[inline]
+    fn next_tpdf(&mut self) -> f64 {
+        self.next_f64() - self.next_f64()
+    }
+}
+
+/// Converts every sample of `src` into the same position of `dst`, by full
+/// scale and rounded to the nearest step of the target.
+///
+/// # Panics
+///
+/// Panics if the two slices have different lengths.
+pub fn convert_samples<T: Sample, U: Sample>(src: &[T], dst: &mut [U]) {
+    assert_eq!(src.len(), dst.len(), "source and destination slices have different lengths");
+    for (d, &s) in dst.iter_mut().zip(src) {
+        *d = U::from_full_scale(s.to_full_scale());
+    }
+}
+
+/// Like [`convert_samples`], but adds TPDF dither from `dither` before
+/// rounding. Float targets are not dithered.
+///
+/// Dither only makes sense when reducing the bit depth, since otherwise
+/// the source already lands on steps of the target.
+///
+/// # Panics
+///
+/// Panics if the two slices have different lengths.
+pub fn convert_samples_dithered<T: Sample, U: Sample>(src: &[T], dst: &mut [U], dither: &mut Dither) {
+    assert_eq!(src.len(), dst.len(), "source and destination slices have different lengths");
+    if U::STEP == 0.0 {
+        return convert_samples(src, dst);
+    }
+    for (d, &s) in dst.iter_mut().zip(src) {
+        *d = U::from_full_scale(s.to_full_scale() + dither.next_tpdf() * U::STEP);
+    }
+}
+
+
+#[test]
+fn full_scale() {
+    let mut out = [0f32; 4];
+    convert_samples(&[i32::MIN, i32::MAX, 1 << 30, 0], &mut out);
+    assert_eq!(out, [-1.0, 1.0, 0.5, 0.0]);
+
+    let mut out = [0i16; 7];
+    convert_samples(&[-1.0f32, 1.0, 2.0, -2.0, f32::NAN, 0.5, 0.25 / 32768.0], &mut out);
+    assert_eq!(out, [i16::MIN, i16::MAX, i16::MAX, i16::MIN, 0, 16384, 0]);
+
+    let mut out = [I24::default(); 4];
+    convert_samples(&[-1.0f32, 1.0, 0.5, -0.5], &mut out);
+    assert_eq!(out.map(I24::get), [I24::MIN, I24::MAX, 1 << 22, -(1 << 22)]);
+
+    let mut out = [0i32; 3];
+    convert_samples(&[i16::MIN, -1, i16::MAX], &mut out);
+    assert_eq!(out, [i32::MIN, -1 << 16, 32767 << 16]);
+    let mut back = [0i16; 3];
+    convert_samples(&out, &mut back);
+    assert_eq!(back, [i16::MIN, -1, i16::MAX]);
+
+    // Down-conversion rounds to nearest.
+    let mut out = [0i16; 3];
+    convert_samples(&[0x7FFFi32, 0x8000, -0x8001], &mut out);
+    assert_eq!(out, [0, 0, -1]);
+}
+
+#[test]
+fn i24_round_trip() {
+    for a in (I24::MIN..=I24::MAX).step_by(251).chain([I24::MIN, -1, 0, 1, I24::MAX]) {
+        assert_eq!(I24::new(a).get(), a);
+        let mut f = [0f32];
+        convert_samples(&[I24::new(a)], &mut f);
+        let mut back = [I24::default()];
+        convert_samples(&f, &mut back);
+        assert_eq!(back[0].get(), a);
+    }
+    assert_eq!(I24::new(I24::MAX + 1).get(), I24::MIN);
+
+    let mut bytes = [0u8; 6];
+    convert_samples(&[1.0f32, -1.0], I24::from_packed_mut(&mut bytes));
+    assert_eq!(bytes, [0xFF, 0xFF, 0x7F, 0x00, 0x00, 0x80]);
+}
+
+#[test]
+#[should_panic(expected = "multiple of 3")]
+fn i24_from_packed_bad_length() {
+    I24::from_packed(&[0; 4]);
+}
+
+#[test]
+fn dither() {
+    let src: std::vec::Vec<i32> = (0..4096).map(|i| (i * 977) << 8).collect();
+    let mut a = std::vec![0i16; src.len()];
+    let mut b = std::vec![0i16; src.len()];
+    convert_samples_dithered(&src, &mut a, &mut Dither::new(42));
+    convert_samples_dithered(&src, &mut b, &mut Dither::new(42));
+    assert_eq!(a, b);
+    convert_samples_dithered(&src, &mut b, &mut Dither::new(43));
+    assert_ne!(a, b);
+
+    // TPDF spans one step either side, and is unbiased.
+    let mut total = 0.0;
+    for (&s, &d) in src.iter().zip(&a) {
+        let error = d as f64 - s as f64 / 65536.0;
+        assert!(error.abs() < 1.5, "{} -> {}", s, d);
+        total += error;
+    }
+    assert!((total / src.len() as f64).abs() < 0.05);
+
+    // A constant half-step input is spread over both neighbours.
+    let src = [0x8000i32; 1000];
+    let mut out = [0i16; 1000];
+    convert_samples_dithered(&src, &mut out, &mut Dither::new(0));
+    assert!(out.contains(&0) && out.contains(&1));
+
+    // Float targets are left alone.
+    let mut out = [0f32; 2];
+    convert_samples_dithered(&[i16::MIN, 16384], &mut out, &mut Dither::new(1));
+    assert_eq!(out, [-1.0, 0.5]);
+}
@@ -0,0 +1,130 @@
+use core::marker::PhantomData;
+
+/// Const-callable versions of [`PrimitiveFrom`](crate::PrimitiveFrom), for
+/// use in `const` items, statics and const generics where trait methods
+/// can't be called.
+///
+/// `Cast<T, U>` has a `const fn cast(T) -> U` for every pair in the
+/// conversion matrix, which behaves exactly like
+/// `<U as PrimitiveFrom<T>>::from`. It is never constructed, it only serves
+/// to name the pair.
+///
+/// With the `nightly` feature, the same conversions are also available as
+/// const trait impls through `ConstPrimitiveFrom`.
+///
+/// # Examples
+///
+/// ```
+/// # use primitive_from::Cast;
+/// const HALF: f32 = Cast::<u8, f32>::cast(128) / 255.0;
+/// const LEN: usize = Cast::<u16, usize>::cast(u16::MAX) + 1;
+/// static BYTES: [u8; LEN / 16384] = [Cast::<i32, u8>::cast(-1); LEN / 16384];
+///
+/// assert_eq!(HALF, 128.0 / 255.0);
+/// assert_eq!(BYTES, [255; 4]);
+/// ```
+pub struct Cast<T, U>(PhantomData<fn(T) -> U>);
+
+macro_rules! impl_const_cast {
+    ($U: ty => $( $T: ty ),* ) => {
+        $(
+        impl Cast<$U, $T> {
+            #[inline] pub const fn cast(a:$U) -> $T { a as $T }
+        }
+        )*
+    };
+    ($U: ty as $V: ty => $( $T: ty ),* ) => {
+        $(
+        impl Cast<$U, $T> {
+            #[inline] pub const fn cast(a:$U) -> $T { a as $V as $T }
+        }
+        )*
+    };
+}
+
+// Every pair of `PrimitiveFrom`, for `$m` to implement. Pairs that `as`
+// doesn't allow go through an intermediate type.
+macro_rules! const_cast_rows {
+    ($m: ident) => {
+        $m!(u8 => char, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+        $m!(i8 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+        $m!(u16 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+        $m!(i16 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+        $m!(u32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+        $m!(i32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+        $m!(u64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+        $m!(i64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+        $m!(usize => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+        $m!(isize => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+        $m!(u128 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+        $m!(i128 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+        $m!(f32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+        $m!(f64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+        $m!(char => char, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+        $m!(bool => bool, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+        $m!(char as u32 => f32, f64);
+        $m!(bool as u8 => f32, f64);
+    };
+}
+
+const_cast_rows!(impl_const_cast);
+
+#[cfg(feature = "nightly")]
+pub(crate) mod nightly;
+
+
+#[cfg(test)]
+macro_rules! test_const_cast {
+    ($U: ty: $a: expr => $( $T: ty ),* ) => {
+        $(
+        assert_eq!(
+            format!("{:?}", Cast::<$U, $T>::cast($a)),
+            format!("{:?}", <$T as crate::PrimitiveFrom<$U>>::from($a)),
+        );
+        )*
+    };
+}
+
+#[test]
+fn const_cast() {
+    const LUT: [f32; 256] = {
+        let mut lut = [0.0; 256];
+        let mut i = 0;
+        while i < 256 {
+            lut[i] = Cast::<usize, f32>::cast(i) / 255.0;
+            i += 1;
+        }
+        lut
+    };
+    static SIGNED: [i8; 4] = [
+        Cast::<u8, i8>::cast(255),
+        Cast::<f64, i8>::cast(-1e10),
+        Cast::<char, i8>::cast('\u{180}'),
+        Cast::<bool, i8>::cast(true),
+    ];
+    const FLAG: f64 = Cast::<bool, f64>::cast(true);
+    const LETTER: char = Cast::<u8, char>::cast(b'A');
+
+    assert_eq!(LUT[0], 0.0);
+    assert_eq!(LUT[255], 1.0);
+    assert_eq!(SIGNED, [-1, i8::MIN, -128, 1]);
+    assert_eq!(FLAG, 1.0);
+    assert_eq!(LETTER, 'A');
+
+    test_const_cast!(u8: 200u8 => char, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_const_cast!(i8: -100i8 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_const_cast!(u16: u16::MAX => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_const_cast!(i16: i16::MIN => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_const_cast!(u32: u32::MAX => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_const_cast!(i32: i32::MIN => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_const_cast!(u64: u64::MAX => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_const_cast!(i64: i64::MIN => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_const_cast!(usize: usize::MAX => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_const_cast!(isize: isize::MIN => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_const_cast!(u128: u128::MAX => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_const_cast!(i128: i128::MIN => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_const_cast!(f32: -1234.5f32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_const_cast!(f64: f64::NAN => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_const_cast!(char: '\u{1F600}' => char, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_const_cast!(bool: true => bool, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+}
@@ -0,0 +1,42 @@
+use super::Cast;
+
+/// A const trait version of [`PrimitiveFrom`](crate::PrimitiveFrom), so
+/// the conversions can be used generically in const contexts. Requires the
+/// `nightly` feature and a nightly compiler.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(const_trait_impl)]
+/// # use primitive_from::ConstPrimitiveFrom;
+/// const fn scale<T: [const] ConstPrimitiveFrom<u8>>(a: u8) -> T {
+///     T::const_from(a)
+/// }
+/// const X: f64 = scale(200);
+/// assert_eq!(X, 200.0);
+/// ```
+pub const trait ConstPrimitiveFrom<T>
+{
+    fn const_from(_:T) -> Self;
+}
+
+macro_rules! impl_const_primitive_from {
+    ($U: ty $(as $V: ty)? => $( $T: ty ),* ) => {
+        $(
+        impl const ConstPrimitiveFrom<$U> for $T {
+            #[inline] fn const_from(a:$U) -> $T { Cast::<$U, $T>::cast(a) }
+        }
+        )*
+    };
+}
+
+const_cast_rows!(impl_const_primitive_from);
+
+
+#[test]
+fn const_primitive_from() {
+    const X: u8 = <u8 as ConstPrimitiveFrom<f32>>::const_from(300.0);
+    const Y: f32 = <f32 as ConstPrimitiveFrom<char>>::const_from('A');
+    assert_eq!(X, u8::MAX);
+    assert_eq!(Y, 65.0);
+}
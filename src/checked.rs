@@ -0,0 +1,205 @@
+use core::convert::TryFrom;
+
+/// A checked version of [`PrimitiveFrom`](crate::PrimitiveFrom), returning
+/// `None` instead of wrapping, truncating or saturating when the source
+/// value does not fit in the target type.
+///
+/// A conversion fails when:
+///
+/// - An integer (or a `char`'s code point) is out of range of the target
+///   integer type.
+/// - A float is NaN, infinite, or its integer part is out of range of the
+///   target integer type. The fractional part is dropped, as with `as`.
+/// - A finite float or integer is too large for the target float type and
+///   would become infinite.
+/// - An integer is not a valid `char`.
+/// - An integer other than 0 or 1 is converted to a `bool`.
+/// - The target is a `NonZero` integer and the value converts to zero.
+///
+/// Between float types NaN and infinities are representable in the target,
+/// so they are passed through unchanged.
+///
+/// # Examples
+///
+/// ```
+/// # use primitive_from::CheckedPrimitiveFrom;
+/// assert_eq!(<u8 as CheckedPrimitiveFrom<i16>>::checked_from(255), Some(255));
+/// assert_eq!(<u8 as CheckedPrimitiveFrom<i16>>::checked_from(768), None);
+/// assert_eq!(<i32 as CheckedPrimitiveFrom<f32>>::checked_from(-3.9), Some(-3));
+/// assert_eq!(<i32 as CheckedPrimitiveFrom<f32>>::checked_from(f32::NAN), None);
+/// assert_eq!(<char as CheckedPrimitiveFrom<u32>>::checked_from(0xD800), None);
+/// ```
+pub trait CheckedPrimitiveFrom<T>: Sized
+{
+    fn checked_from(_:T) -> Option<Self>;
+}
+
+macro_rules! impl_checked_primitive_from {
+    (int $U: ty => $( $T: ty ),* ) => {
+        $(
+        impl CheckedPrimitiveFrom<$U> for $T {
+            #[inline] fn checked_from(a:$U) -> Option<$T> { <$T>::try_from(a).ok() }
+        }
+        )*
+    };
+    (int_to_float $U: ty => $( $T: ty ),* ) => {
+        $(
+        impl CheckedPrimitiveFrom<$U> for $T {
+            #[inline] fn checked_from(a:$U) -> Option<$T> {
+                let b = a as $T;
+                if b.is_infinite() { None } else { Some(b) }
+            }
+        }
+        )*
+    };
+    (float_to_int $U: ty => $( $T: ty ),* ) => {
+        $(
+        impl CheckedPrimitiveFrom<$U> for $T {
+            #[inline] fn checked_from(a:$U) -> Option<$T> {
+                // `MIN as $U - 1.0` rounds back to `MIN` when it is not
+                // representable, hence the extra `>=` comparison.
+                let min = <$T>::MIN as $U;
+                if (a >= min || a > min - 1.0) && a < <$T>::MAX as $U + 1.0 {
+                    Some(a as $T)
+                } else {
+                    None
+                }
+            }
+        }
+        )*
+    };
+    (float $U: ty => $( $T: ty ),* ) => {
+        $(
+        impl CheckedPrimitiveFrom<$U> for $T {
+            #[inline] fn checked_from(a:$U) -> Option<$T> {
+                let b = a as $T;
+                if b.is_infinite() && a.is_finite() { None } else { Some(b) }
+            }
+        }
+        )*
+    };
+    (char => $( $T: ty ),* ) => {
+        $(
+        impl CheckedPrimitiveFrom<char> for $T {
+            #[inline] fn checked_from(a:char) -> Option<$T> { CheckedPrimitiveFrom::checked_from(a as u32) }
+        }
+        )*
+    };
+    (int_to_char $( $U: ty ),* ) => {
+        $(
+        impl CheckedPrimitiveFrom<$U> for char {
+            #[inline] fn checked_from(a:$U) -> Option<char> {
+                u32::try_from(a).ok().and_then(core::char::from_u32)
+            }
+        }
+        )*
+    };
+    (int_to_bool $( $U: ty ),* ) => {
+        $(
+        impl CheckedPrimitiveFrom<$U> for bool {
+            #[inline] fn checked_from(a:$U) -> Option<bool> {
+                match a {
+                    0 => Some(false),
+                    1 => Some(true),
+                    _ => None,
+                }
+            }
+        }
+        )*
+    };
+    (bool => $( $T: ty ),* ) => {
+        $(
+        impl CheckedPrimitiveFrom<bool> for $T {
+            #[inline] fn checked_from(a:bool) -> Option<$T> { Some(crate::PrimitiveFrom::from(a)) }
+        }
+        )*
+    };
+}
+
+impl_checked_primitive_from!(int u8 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_checked_primitive_from!(int i8 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_checked_primitive_from!(int u16 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_checked_primitive_from!(int i16 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_checked_primitive_from!(int u32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_checked_primitive_from!(int i32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_checked_primitive_from!(int u64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_checked_primitive_from!(int i64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_checked_primitive_from!(int usize => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_checked_primitive_from!(int isize => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_checked_primitive_from!(int u128 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_checked_primitive_from!(int i128 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_checked_primitive_from!(int_to_float u8 => f32, f64);
+impl_checked_primitive_from!(int_to_float i8 => f32, f64);
+impl_checked_primitive_from!(int_to_float u16 => f32, f64);
+impl_checked_primitive_from!(int_to_float i16 => f32, f64);
+impl_checked_primitive_from!(int_to_float u32 => f32, f64);
+impl_checked_primitive_from!(int_to_float i32 => f32, f64);
+impl_checked_primitive_from!(int_to_float u64 => f32, f64);
+impl_checked_primitive_from!(int_to_float i64 => f32, f64);
+impl_checked_primitive_from!(int_to_float usize => f32, f64);
+impl_checked_primitive_from!(int_to_float isize => f32, f64);
+impl_checked_primitive_from!(int_to_float u128 => f32, f64);
+impl_checked_primitive_from!(int_to_float i128 => f32, f64);
+impl_checked_primitive_from!(float_to_int f32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_checked_primitive_from!(float_to_int f64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_checked_primitive_from!(float f32 => f32, f64);
+impl_checked_primitive_from!(float f64 => f32, f64);
+impl_checked_primitive_from!(char => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_checked_primitive_from!(bool => bool, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_checked_primitive_from!(int_to_char i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_checked_primitive_from!(int_to_bool u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+
+impl CheckedPrimitiveFrom<u8> for char {
+    #[inline] fn checked_from(a:u8) -> Option<char> { Some(a as char) }
+}
+
+impl CheckedPrimitiveFrom<char> for char {
+    #[inline] fn checked_from(a:char) -> Option<char> { Some(a) }
+}
+
+
+#[test]
+fn checked_primitive_from() {
+    assert_eq!(<u8 as CheckedPrimitiveFrom<i16>>::checked_from(768), None);
+    assert_eq!(<u8 as CheckedPrimitiveFrom<i16>>::checked_from(-1), None);
+    assert_eq!(<i8 as CheckedPrimitiveFrom<u128>>::checked_from(127), Some(127));
+    assert_eq!(<u128 as CheckedPrimitiveFrom<i128>>::checked_from(i128::MIN), None);
+
+    assert_eq!(<u8 as CheckedPrimitiveFrom<f32>>::checked_from(255.9), Some(255));
+    assert_eq!(<u8 as CheckedPrimitiveFrom<f32>>::checked_from(256.0), None);
+    assert_eq!(<u8 as CheckedPrimitiveFrom<f32>>::checked_from(-0.9), Some(0));
+    assert_eq!(<u8 as CheckedPrimitiveFrom<f32>>::checked_from(-1.0), None);
+    assert_eq!(<i8 as CheckedPrimitiveFrom<f64>>::checked_from(-128.9), Some(-128));
+    assert_eq!(<i8 as CheckedPrimitiveFrom<f64>>::checked_from(-129.0), None);
+    assert_eq!(<i32 as CheckedPrimitiveFrom<f32>>::checked_from(-2147483648.0), Some(i32::MIN));
+    assert_eq!(<i32 as CheckedPrimitiveFrom<f32>>::checked_from(2147483648.0), None);
+    assert_eq!(<u64 as CheckedPrimitiveFrom<f64>>::checked_from(18446744073709549568.0), Some(18446744073709549568));
+    assert_eq!(<u64 as CheckedPrimitiveFrom<f64>>::checked_from(18446744073709551616.0), None);
+    assert_eq!(<u128 as CheckedPrimitiveFrom<f32>>::checked_from(f32::MAX), Some(f32::MAX as u128));
+    assert_eq!(<i64 as CheckedPrimitiveFrom<f64>>::checked_from(f64::NAN), None);
+    assert_eq!(<i64 as CheckedPrimitiveFrom<f64>>::checked_from(f64::INFINITY), None);
+    assert_eq!(<u32 as CheckedPrimitiveFrom<f32>>::checked_from(f32::NEG_INFINITY), None);
+
+    assert_eq!(<f32 as CheckedPrimitiveFrom<u128>>::checked_from(u128::MAX), None);
+    assert_eq!(<f32 as CheckedPrimitiveFrom<u64>>::checked_from(u64::MAX), Some(u64::MAX as f32));
+    assert_eq!(<f32 as CheckedPrimitiveFrom<f64>>::checked_from(1e300), None);
+    assert_eq!(<f32 as CheckedPrimitiveFrom<f64>>::checked_from(f64::INFINITY), Some(f32::INFINITY));
+    assert!(<f32 as CheckedPrimitiveFrom<f64>>::checked_from(f64::NAN).unwrap().is_nan());
+
+    assert_eq!(<char as CheckedPrimitiveFrom<u32>>::checked_from(0x41), Some('A'));
+    assert_eq!(<char as CheckedPrimitiveFrom<u32>>::checked_from(0xD800), None);
+    assert_eq!(<char as CheckedPrimitiveFrom<u32>>::checked_from(0x110000), None);
+    assert_eq!(<u8 as CheckedPrimitiveFrom<char>>::checked_from('\u{FF}'), Some(255));
+    assert_eq!(<u8 as CheckedPrimitiveFrom<char>>::checked_from('\u{100}'), None);
+    assert_eq!(<i8 as CheckedPrimitiveFrom<bool>>::checked_from(true), Some(1));
+    assert_eq!(<f32 as CheckedPrimitiveFrom<bool>>::checked_from(true), Some(1.0));
+    assert_eq!(<f64 as CheckedPrimitiveFrom<char>>::checked_from('\u{10FFFF}'), Some(1114111.0));
+
+    assert_eq!(<char as CheckedPrimitiveFrom<i64>>::checked_from(0x1F600), Some('\u{1F600}'));
+    assert_eq!(<char as CheckedPrimitiveFrom<i8>>::checked_from(-1), None);
+    assert_eq!(<char as CheckedPrimitiveFrom<u128>>::checked_from(1 << 32 | 0x41), None);
+    assert_eq!(<bool as CheckedPrimitiveFrom<u8>>::checked_from(0), Some(false));
+    assert_eq!(<bool as CheckedPrimitiveFrom<i128>>::checked_from(1), Some(true));
+    assert_eq!(<bool as CheckedPrimitiveFrom<i32>>::checked_from(-1), None);
+    assert_eq!(<bool as CheckedPrimitiveFrom<usize>>::checked_from(2), None);
+}
@@ -0,0 +1,88 @@
+//! Element-wise conversions for arrays and tuples.
+//!
+//! `[T; N]` converts to `[U; N]` and `(A, B, ..)` to `(X, Y, ..)`, up to
+//! 12 elements, whenever every element converts. The checked version only
+//! succeeds if every element does.
+
+use crate::{CheckedPrimitiveFrom, PrimitiveFrom, SaturatingPrimitiveFrom};
+
+impl<T, U: PrimitiveFrom<T>, const N: usize> PrimitiveFrom<[T; N]> for [U; N] {
+    #[inline] fn from(a:[T; N]) -> [U; N] { a.map(<U as PrimitiveFrom<T>>::from) }
+}
+
+impl<T, U: CheckedPrimitiveFrom<T>, const N: usize> CheckedPrimitiveFrom<[T; N]> for [U; N] {
+    #[inline] fn checked_from(a:[T; N]) -> Option<[U; N]> {
+        let b = a.map(<U as CheckedPrimitiveFrom<T>>::checked_from);
+        if b.iter().any(Option::is_none) {
+            return None;
+        }
+        Some(b.map(Option::unwrap))
+    }
+}
+
+impl<T, U: SaturatingPrimitiveFrom<T>, const N: usize> SaturatingPrimitiveFrom<[T; N]> for [U; N] {
+    #[inline] fn saturating_from(a:[T; N]) -> [U; N] { a.map(<U as SaturatingPrimitiveFrom<T>>::saturating_from) }
+}
+
+macro_rules! impl_tuple_primitive_from {
+    ( $( ($( $T: ident => $U: ident . $i: tt ),+) )* ) => {
+        $(
+        impl<$($T, $U: PrimitiveFrom<$T>),+> PrimitiveFrom<($($T,)+)> for ($($U,)+) {
+            #[inline] fn from(a:($($T,)+)) -> ($($U,)+) { ($(<$U as PrimitiveFrom<$T>>::from(a.$i),)+) }
+        }
+
+        impl<$($T, $U: CheckedPrimitiveFrom<$T>),+> CheckedPrimitiveFrom<($($T,)+)> for ($($U,)+) {
+            #[inline] fn checked_from(a:($($T,)+)) -> Option<($($U,)+)> {
+                Some(($(<$U as CheckedPrimitiveFrom<$T>>::checked_from(a.$i)?,)+))
+            }
+        }
+
+        impl<$($T, $U: SaturatingPrimitiveFrom<$T>),+> SaturatingPrimitiveFrom<($($T,)+)> for ($($U,)+) {
+            #[inline] fn saturating_from(a:($($T,)+)) -> ($($U,)+) {
+                ($(<$U as SaturatingPrimitiveFrom<$T>>::saturating_from(a.$i),)+)
+            }
+        }
+        )*
+    };
+}
+
+impl_tuple_primitive_from! {
+    (T0 => U0.0)
+    (T0 => U0.0, T1 => U1.1)
+    (T0 => U0.0, T1 => U1.1, T2 => U2.2)
+    (T0 => U0.0, T1 => U1.1, T2 => U2.2, T3 => U3.3)
+    (T0 => U0.0, T1 => U1.1, T2 => U2.2, T3 => U3.3, T4 => U4.4)
+    (T0 => U0.0, T1 => U1.1, T2 => U2.2, T3 => U3.3, T4 => U4.4, T5 => U5.5)
+    (T0 => U0.0, T1 => U1.1, T2 => U2.2, T3 => U3.3, T4 => U4.4, T5 => U5.5, T6 => U6.6)
+    (T0 => U0.0, T1 => U1.1, T2 => U2.2, T3 => U3.3, T4 => U4.4, T5 => U5.5, T6 => U6.6, T7 => U7.7)
+    (T0 => U0.0, T1 => U1.1, T2 => U2.2, T3 => U3.3, T4 => U4.4, T5 => U5.5, T6 => U6.6, T7 => U7.7, T8 => U8.8)
+    (T0 => U0.0, T1 => U1.1, T2 => U2.2, T3 => U3.3, T4 => U4.4, T5 => U5.5, T6 => U6.6, T7 => U7.7, T8 => U8.8, T9 => U9.9)
+    (T0 => U0.0, T1 => U1.1, T2 => U2.2, T3 => U3.3, T4 => U4.4, T5 => U5.5, T6 => U6.6, T7 => U7.7, T8 => U8.8, T9 => U9.9, T10 => U10.10)
+    (T0 => U0.0, T1 => U1.1, T2 => U2.2, T3 => U3.3, T4 => U4.4, T5 => U5.5, T6 => U6.6, T7 => U7.7, T8 => U8.8, T9 => U9.9, T10 => U10.10, T11 => U11.11)
+}
+
+
+#[test]
+fn compound_primitive_from() {
+    let a: [f32; 3] = PrimitiveFrom::from([1.5f64, -2.25, 1e300]);
+    assert_eq!(a, [1.5, -2.25, f32::INFINITY]);
+    let t: (f32, f32) = PrimitiveFrom::from((3i32, -4i32));
+    assert_eq!(t, (3.0, -4.0));
+    let t: (u8, char, f64) = PrimitiveFrom::from((300u16, 65u8, true));
+    assert_eq!(t, (44, 'A', 1.0));
+    let t: (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, i8) =
+        PrimitiveFrom::from((0i32, 1i32, 2i32, 3i32, 4i32, 5i32, 6i32, 7i32, 8i32, 9i32, 10i32, 255u8));
+    assert_eq!(t, (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, -1));
+
+    assert_eq!(<[u8; 3] as CheckedPrimitiveFrom<[i32; 3]>>::checked_from([1, 2, 3]), Some([1, 2, 3]));
+    assert_eq!(<[u8; 3] as CheckedPrimitiveFrom<[i32; 3]>>::checked_from([1, 256, 3]), None);
+    assert_eq!(<[u8; 0] as CheckedPrimitiveFrom<[i32; 0]>>::checked_from([]), Some([]));
+    assert_eq!(<(u8, i8) as CheckedPrimitiveFrom<(f32, i64)>>::checked_from((2.5, -7)), Some((2, -7)));
+    assert_eq!(<(u8, i8) as CheckedPrimitiveFrom<(f32, i64)>>::checked_from((2.5, -700)), None);
+
+    assert_eq!(<[i16; 3] as SaturatingPrimitiveFrom<[f32; 3]>>::saturating_from([1e9, -1e9, 0.5]), [i16::MAX, i16::MIN, 0]);
+    assert_eq!(<(u8, f32) as SaturatingPrimitiveFrom<(i32, f64)>>::saturating_from((-1, 1e300)), (0, f32::MAX));
+
+    let v: [[f32; 2]; 2] = PrimitiveFrom::from([[1u8, 2], [3, 4]]);
+    assert_eq!(v, [[1.0, 2.0], [3.0, 4.0]]);
+}
@@ -0,0 +1,182 @@
+//! Byte orders for [`ReadPrimitiveFrom`] and [`WritePrimitiveInto`].
+
+use crate::{CheckedPrimitiveFrom, PrimitiveFrom};
+
+/// Least significant byte first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LittleEndian;
+
+/// Most significant byte first, also known as network byte order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BigEndian;
+
+/// The byte order of the target platform.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NativeEndian;
+
+/// A number with a fixed size byte representation, which is every integer
+/// and float in the matrix.
+pub trait Bytes: Copy
+{
+    /// `[u8; N]`, where `N` is the size of the type.
+    type Array: Copy + Default + AsRef<[u8]> + AsMut<[u8]>;
+
+    fn from_le_array(_:Self::Array) -> Self;
+    fn from_be_array(_:Self::Array) -> Self;
+    fn from_ne_array(_:Self::Array) -> Self;
+    fn to_le_array(self) -> Self::Array;
+    fn to_be_array(self) -> Self::Array;
+    fn to_ne_array(self) -> Self::Array;
+}
+
+macro_rules! impl_bytes {
+    ($( $T: ty ),* ) => {
+        $(
+        impl Bytes for $T {
+            type Array = [u8; core::mem::size_of::<$T>()];
+
+            #[inline] fn from_le_array(a:Self::Array) -> $T { <$T>::from_le_bytes(a) }
+            #[inline] fn from_be_array(a:Self::Array) -> $T { <$T>::from_be_bytes(a) }
+            #[inline] fn from_ne_array(a:Self::Array) -> $T { <$T>::from_ne_bytes(a) }
+            #[inline] fn to_le_array(self) -> Self::Array { self.to_le_bytes() }
+            #[inline] fn to_be_array(self) -> Self::Array { self.to_be_bytes() }
+            #[inline] fn to_ne_array(self) -> Self::Array { self.to_ne_bytes() }
+        }
+        )*
+    };
+}
+
+impl_bytes!(u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+
+/// A byte order, one of the zero-sized types in [`endian`](self).
+pub trait Endian
+{
+    fn read<S: Bytes>(_:S::Array) -> S;
+    fn write<S: Bytes>(_:S) -> S::Array;
+}
+
+macro_rules! impl_endian {
+    ($( $E: ty: $from: ident, $to: ident ),* ) => {
+        $(
+        impl Endian for $E {
+            #[inline] fn read<S: Bytes>(a:S::Array) -> S { S::$from(a) }
+            #[inline] fn write<S: Bytes>(a:S) -> S::Array { a.$to() }
+        }
+        )*
+    };
+}
+
+impl_endian!(LittleEndian: from_le_array, to_le_array, BigEndian: from_be_array, to_be_array, NativeEndian: from_ne_array, to_ne_array);
+
+/// Reads a number of type `S` from bytes in the byte order `E`, and casts
+/// it with [`PrimitiveFrom`] in the same step.
+///
+/// It is implemented for every type that can be cast from `S`.
+///
+/// # Examples
+///
+/// ```
+/// # use primitive_from::ReadPrimitiveFrom;
+/// use primitive_from::endian::{BigEndian, LittleEndian};
+///
+/// // Read a big-endian u16 and widen it to i64.
+/// assert_eq!(<i64 as ReadPrimitiveFrom<u16, BigEndian>>::read_from([0x01, 0x02]), 0x0102);
+///
+/// let header = [0x00, 0x00, 0x80, 0x3F, 0xFF, 0x00];
+/// assert_eq!(<f64 as ReadPrimitiveFrom<f32, LittleEndian>>::read_from_slice(&header), Some(1.0));
+/// assert_eq!(<u8 as ReadPrimitiveFrom<u32, LittleEndian>>::read_from_slice(&header[2..]), Some(0x80));
+/// assert_eq!(<u8 as ReadPrimitiveFrom<u64, LittleEndian>>::read_from_slice(&header), None);
+/// ```
+pub trait ReadPrimitiveFrom<S: Bytes, E: Endian>: PrimitiveFrom<S> + Sized
+{
+    /// Reads `S` from exactly its own size in bytes.
+    #[inline]
+    fn read_from(bytes: S::Array) -> Self {
+        Self::from(E::read::<S>(bytes))
+    }
+
+    /// Reads `S` from the start of `bytes`, ignoring anything after it.
+    /// Returns `None` if `bytes` is shorter than `S`.
+    #[inline]
+    fn read_from_slice(bytes: &[u8]) -> Option<Self> {
+        let mut a = S::Array::default();
+        let n = a.as_ref().len();
+        a.as_mut().copy_from_slice(bytes.get(..n)?);
+        Some(Self::read_from(a))
+    }
+}
+
+impl<S: Bytes, E: Endian, T: PrimitiveFrom<S>> ReadPrimitiveFrom<S, E> for T {}
+
+/// Converts a value to a number of type `S` with [`CheckedPrimitiveFrom`],
+/// and writes it as bytes in the byte order `E`.
+///
+/// It is implemented for every type that `S` can be checked-cast from. The
+/// write fails instead of narrowing a value that does not fit in `S`.
+///
+/// # Examples
+///
+/// ```
+/// # use primitive_from::WritePrimitiveInto;
+/// use primitive_from::endian::{BigEndian, LittleEndian};
+///
+/// assert_eq!(<i64 as WritePrimitiveInto<u16, BigEndian>>::write_into(0x0102), Some([0x01, 0x02]));
+/// assert_eq!(<i64 as WritePrimitiveInto<u16, BigEndian>>::write_into(-1), None);
+///
+/// let mut packet = [0u8; 6];
+/// assert_eq!(WritePrimitiveInto::<u32, LittleEndian>::write_into_slice(7usize, &mut packet[2..]), Some(()));
+/// assert_eq!(packet, [0, 0, 7, 0, 0, 0]);
+/// ```
+pub trait WritePrimitiveInto<S: Bytes, E: Endian>: Sized
+{
+    /// The bytes of the value as `S`, or `None` if it does not fit.
+    fn write_into(self) -> Option<S::Array>;
+
+    /// Writes the value as `S` to the start of `dst`, leaving the rest of
+    /// it alone. Returns `None` and writes nothing if the value does not
+    /// fit, or if `dst` is shorter than `S`.
+    #[inline]
+    fn write_into_slice(self, dst: &mut [u8]) -> Option<()> {
+        let a = self.write_into()?;
+        let a = a.as_ref();
+        dst.get_mut(..a.len())?.copy_from_slice(a);
+        Some(())
+    }
+}
+
+impl<S: Bytes + CheckedPrimitiveFrom<T>, E: Endian, T> WritePrimitiveInto<S, E> for T {
+    #[inline] fn write_into(self) -> Option<S::Array> { Some(E::write(S::checked_from(self)?)) }
+}
+
+
+#[test]
+fn read_primitive_from() {
+    assert_eq!(<i64 as ReadPrimitiveFrom<i16, BigEndian>>::read_from([0xFF, 0xFE]), -2);
+    assert_eq!(<i64 as ReadPrimitiveFrom<u16, BigEndian>>::read_from([0xFF, 0xFE]), 0xFFFE);
+    assert_eq!(<u8 as ReadPrimitiveFrom<u32, LittleEndian>>::read_from([0x34, 0x12, 0, 0]), 0x34);
+    assert_eq!(<f32 as ReadPrimitiveFrom<u32, NativeEndian>>::read_from(7u32.to_ne_bytes()), 7.0);
+    assert_eq!(<i32 as ReadPrimitiveFrom<f64, BigEndian>>::read_from((-2.5f64).to_be_bytes()), -2);
+    assert_eq!(<char as ReadPrimitiveFrom<u8, BigEndian>>::read_from([b'A']), 'A');
+    assert_eq!(<u128 as ReadPrimitiveFrom<u128, LittleEndian>>::read_from_slice(&[0xAB; 17]), Some(u128::from_le_bytes([0xAB; 16])));
+    assert_eq!(<u128 as ReadPrimitiveFrom<u128, LittleEndian>>::read_from_slice(&[0xAB; 15]), None);
+    assert_eq!(<i8 as ReadPrimitiveFrom<i8, LittleEndian>>::read_from_slice(&[]), None);
+}
+
+#[test]
+fn write_primitive_into() {
+    assert_eq!(<u64 as WritePrimitiveInto<u32, BigEndian>>::write_into(0x0102_0304), Some([1, 2, 3, 4]));
+    assert_eq!(<u64 as WritePrimitiveInto<u32, LittleEndian>>::write_into(0x0102_0304), Some([4, 3, 2, 1]));
+    assert_eq!(<u64 as WritePrimitiveInto<u32, BigEndian>>::write_into(1 << 32), None);
+    assert_eq!(<f64 as WritePrimitiveInto<i16, BigEndian>>::write_into(-1.5), Some([0xFF, 0xFF]));
+    assert_eq!(<f64 as WritePrimitiveInto<i16, BigEndian>>::write_into(f64::NAN), None);
+    assert_eq!(<f64 as WritePrimitiveInto<f32, LittleEndian>>::write_into(0.5), Some(0.5f32.to_le_bytes()));
+    assert_eq!(<f64 as WritePrimitiveInto<f32, LittleEndian>>::write_into(1e300), None);
+    assert_eq!(<char as WritePrimitiveInto<u16, NativeEndian>>::write_into('\u{FFFF}'), Some([0xFF, 0xFF]));
+
+    let mut buf = [9u8; 3];
+    assert_eq!(<i32 as WritePrimitiveInto<u16, BigEndian>>::write_into_slice(0x1234, &mut buf[1..]), Some(()));
+    assert_eq!(buf, [9, 0x12, 0x34]);
+    assert_eq!(<i32 as WritePrimitiveInto<u16, BigEndian>>::write_into_slice(0x1234, &mut buf[2..]), None);
+    assert_eq!(<i32 as WritePrimitiveInto<u8, BigEndian>>::write_into_slice(300, &mut buf), None);
+    assert_eq!(buf, [9, 0x12, 0x34]);
+}
@@ -0,0 +1,143 @@
+use crate::{CheckedPrimitiveFrom, TryPrimitiveFrom, TryPrimitiveFromError};
+
+/// A fallible version of [`PrimitiveFrom`](crate::PrimitiveFrom) that only
+/// succeeds when no information is lost, that is when converting the result
+/// back gives the original value.
+///
+/// On top of the range errors reported by [`TryPrimitiveFrom`], it fails
+/// with [`TryPrimitiveFromError::PrecisionLoss`] when:
+///
+/// - A float with a fractional part is converted to an integer.
+/// - An integer is converted to a float that cannot represent it exactly,
+///   like `u64 -> f32` or `i64 -> f64` above 2^53.
+/// - A `f64` is narrowed to a `f32` that cannot represent it exactly.
+///
+/// Between float types NaN is passed through, since NaN never compares
+/// equal to itself.
+///
+/// # Examples
+///
+/// ```
+/// # use primitive_from::{ExactPrimitiveFrom, TryPrimitiveFromError};
+/// assert_eq!(<f64 as ExactPrimitiveFrom<i32>>::exact_from(i32::MIN), Ok(-2147483648.0));
+/// assert_eq!(<f32 as ExactPrimitiveFrom<f64>>::exact_from(0.5), Ok(0.5));
+/// assert_eq!(
+///     <f32 as ExactPrimitiveFrom<f64>>::exact_from(0.1),
+///     Err(TryPrimitiveFromError::PrecisionLoss { value: 0.1, target: "f32" })
+/// );
+/// assert_eq!(
+///     <i64 as ExactPrimitiveFrom<f64>>::exact_from(2.5),
+///     Err(TryPrimitiveFromError::PrecisionLoss { value: 2.5, target: "i64" })
+/// );
+/// ```
+pub trait ExactPrimitiveFrom<T>: Sized
+{
+    fn exact_from(_:T) -> Result<Self, TryPrimitiveFromError<T>>;
+}
+
+macro_rules! impl_exact_primitive_from {
+    (round_trip $U: ty => $( $T: ty ),* ) => {
+        $(
+        impl ExactPrimitiveFrom<$U> for $T {
+            #[inline] fn exact_from(a:$U) -> Result<$T, TryPrimitiveFromError<$U>> {
+                let b = <$T as TryPrimitiveFrom<$U>>::try_from(a)?;
+                if <$U as CheckedPrimitiveFrom<$T>>::checked_from(b) == Some(a) {
+                    Ok(b)
+                } else {
+                    Err(TryPrimitiveFromError::PrecisionLoss { value: a, target: stringify!($T) })
+                }
+            }
+        }
+        )*
+    };
+    (float $U: ty => $( $T: ty ),* ) => {
+        $(
+        impl ExactPrimitiveFrom<$U> for $T {
+            #[inline] fn exact_from(a:$U) -> Result<$T, TryPrimitiveFromError<$U>> {
+                let b = <$T as TryPrimitiveFrom<$U>>::try_from(a)?;
+                if a.is_nan() || b as $U == a {
+                    Ok(b)
+                } else {
+                    Err(TryPrimitiveFromError::PrecisionLoss { value: a, target: stringify!($T) })
+                }
+            }
+        }
+        )*
+    };
+    // Conversions that can only fail on range.
+    (range $U: ty => $( $T: ty ),* ) => {
+        $(
+        impl ExactPrimitiveFrom<$U> for $T {
+            #[inline] fn exact_from(a:$U) -> Result<$T, TryPrimitiveFromError<$U>> {
+                <$T as TryPrimitiveFrom<$U>>::try_from(a)
+            }
+        }
+        )*
+    };
+}
+
+impl_exact_primitive_from!(round_trip u8 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_exact_primitive_from!(round_trip i8 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_exact_primitive_from!(round_trip u16 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_exact_primitive_from!(round_trip i16 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_exact_primitive_from!(round_trip u32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_exact_primitive_from!(round_trip i32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_exact_primitive_from!(round_trip u64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_exact_primitive_from!(round_trip i64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_exact_primitive_from!(round_trip usize => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_exact_primitive_from!(round_trip isize => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_exact_primitive_from!(round_trip u128 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_exact_primitive_from!(round_trip i128 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_exact_primitive_from!(round_trip f32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_exact_primitive_from!(round_trip f64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_exact_primitive_from!(float f32 => f32, f64);
+impl_exact_primitive_from!(float f64 => f32, f64);
+impl_exact_primitive_from!(range char => char, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_exact_primitive_from!(range bool => bool, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_exact_primitive_from!(range i8 => char, bool);
+impl_exact_primitive_from!(range u16 => char, bool);
+impl_exact_primitive_from!(range i16 => char, bool);
+impl_exact_primitive_from!(range u32 => char, bool);
+impl_exact_primitive_from!(range i32 => char, bool);
+impl_exact_primitive_from!(range u64 => char, bool);
+impl_exact_primitive_from!(range i64 => char, bool);
+impl_exact_primitive_from!(range usize => char, bool);
+impl_exact_primitive_from!(range isize => char, bool);
+impl_exact_primitive_from!(range u128 => char, bool);
+impl_exact_primitive_from!(range i128 => char, bool);
+impl_exact_primitive_from!(range u8 => bool);
+
+impl ExactPrimitiveFrom<u8> for char {
+    #[inline] fn exact_from(a:u8) -> Result<char, TryPrimitiveFromError<u8>> { Ok(a as char) }
+}
+
+
+#[test]
+fn exact_primitive_from() {
+    use TryPrimitiveFromError::*;
+
+    assert_eq!(<f32 as ExactPrimitiveFrom<u16>>::exact_from(u16::MAX), Ok(65535.0));
+    assert_eq!(<f32 as ExactPrimitiveFrom<u32>>::exact_from(16777216), Ok(16777216.0));
+    assert_eq!(<f32 as ExactPrimitiveFrom<u32>>::exact_from(16777217), Err(PrecisionLoss { value: 16777217, target: "f32" }));
+    assert_eq!(<f32 as ExactPrimitiveFrom<u64>>::exact_from(u64::MAX), Err(PrecisionLoss { value: u64::MAX, target: "f32" }));
+    assert_eq!(<f64 as ExactPrimitiveFrom<i64>>::exact_from(1 << 53), Ok(9007199254740992.0));
+    assert_eq!(<f64 as ExactPrimitiveFrom<i64>>::exact_from((1 << 53) + 1), Err(PrecisionLoss { value: (1 << 53) + 1, target: "f64" }));
+    assert_eq!(<f64 as ExactPrimitiveFrom<i64>>::exact_from(i64::MIN), Ok(-9223372036854775808.0));
+    assert_eq!(<f32 as ExactPrimitiveFrom<u128>>::exact_from(u128::MAX), Err(Overflow { value: u128::MAX, target: "f32" }));
+
+    assert_eq!(<u8 as ExactPrimitiveFrom<f32>>::exact_from(255.0), Ok(255));
+    assert_eq!(<u8 as ExactPrimitiveFrom<f32>>::exact_from(254.5), Err(PrecisionLoss { value: 254.5, target: "u8" }));
+    assert_eq!(<u8 as ExactPrimitiveFrom<f32>>::exact_from(-0.5), Err(PrecisionLoss { value: -0.5, target: "u8" }));
+    assert_eq!(<u8 as ExactPrimitiveFrom<f32>>::exact_from(256.0), Err(Overflow { value: 256.0, target: "u8" }));
+
+    assert_eq!(<f32 as ExactPrimitiveFrom<f64>>::exact_from(f64::INFINITY), Ok(f32::INFINITY));
+    assert_eq!(<f32 as ExactPrimitiveFrom<f64>>::exact_from(1e300), Err(Overflow { value: 1e300, target: "f32" }));
+    assert!(<f32 as ExactPrimitiveFrom<f64>>::exact_from(f64::NAN).unwrap().is_nan());
+
+    assert_eq!(<i8 as ExactPrimitiveFrom<u64>>::exact_from(200), Err(Overflow { value: 200, target: "i8" }));
+    assert_eq!(<char as ExactPrimitiveFrom<u32>>::exact_from(0x1F600), Ok('\u{1F600}'));
+    assert_eq!(<u16 as ExactPrimitiveFrom<char>>::exact_from('\u{1F600}'), Err(Overflow { value: '\u{1F600}', target: "u16" }));
+    assert_eq!(<f32 as ExactPrimitiveFrom<char>>::exact_from('\u{10FFFF}'), Ok(1114111.0));
+    assert_eq!(<bool as ExactPrimitiveFrom<i32>>::exact_from(1), Ok(true));
+    assert_eq!(<bool as ExactPrimitiveFrom<i32>>::exact_from(3), Err(Overflow { value: 3, target: "bool" }));
+}
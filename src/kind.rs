@@ -0,0 +1,235 @@
+use crate::PrimitiveFrom;
+
+/// What a [`PrimitiveFrom`] cast can do to a value, known at compile time.
+///
+/// It is implemented for every pair of primitives in the matrix. `usize` and
+/// `isize` are classified for the pointer width of the target, so
+/// `usize -> u64` is lossless on 64-bit targets only.
+///
+/// # Examples
+///
+/// ```
+/// # use primitive_from::ConversionKind;
+/// const _: () = assert!(<f64 as ConversionKind<i32>>::LOSSLESS);
+/// const _: () = assert!(<f32 as ConversionKind<i32>>::MAY_LOSE_PRECISION);
+/// const _: () = assert!(<u8 as ConversionKind<i8>>::MAY_CHANGE_SIGN);
+/// const _: () = assert!(<i8 as ConversionKind<i16>>::MAY_TRUNCATE);
+///
+/// fn widen<T, U: ConversionKind<T>>(a: T) -> U {
+///     assert!(U::LOSSLESS, "not a widening cast");
+///     U::from(a)
+/// }
+/// assert_eq!(widen::<u16, i32>(u16::MAX), 65535);
+/// ```
+pub trait ConversionKind<T>: PrimitiveFrom<T>
+{
+    /// Every value converts exactly, so none of the other flags are set.
+    const LOSSLESS: bool;
+    /// Some values are out of range of the target, and are wrapped,
+    /// saturated or become infinite. This includes every float to integer
+    /// cast, since NaN and infinities have no integer value.
+    const MAY_TRUNCATE: bool;
+    /// Some values fall between two values of the target and are rounded,
+    /// like large integers cast to a float, or any float cast to an integer.
+    const MAY_LOSE_PRECISION: bool;
+    /// Some values change sign, like negative values cast to an unsigned
+    /// type, or integers that wrap into the sign bit of a smaller type.
+    const MAY_CHANGE_SIGN: bool;
+}
+
+/// How a primitive represents its values.
+#[derive(Clone, Copy)]
+struct Repr {
+    float: bool,
+    signed: bool,
+    /// Bits of precision: the value bits of an integer, or the mantissa
+    /// digits of a float.
+    digits: u32,
+    /// Every finite value is less than `2^max_exp` in magnitude.
+    max_exp: u32,
+}
+
+trait HasRepr {
+    const REPR: Repr;
+}
+
+macro_rules! impl_has_repr {
+    (unsigned $( $T: ty ),* ) => {
+        $( impl HasRepr for $T { const REPR: Repr = Repr { float: false, signed: false, digits: <$T>::BITS, max_exp: <$T>::BITS }; } )*
+    };
+    (signed $( $T: ty ),* ) => {
+        $( impl HasRepr for $T { const REPR: Repr = Repr { float: false, signed: true, digits: <$T>::BITS - 1, max_exp: <$T>::BITS - 1 }; } )*
+    };
+    (float $( $T: ty ),* ) => {
+        $( impl HasRepr for $T { const REPR: Repr = Repr { float: true, signed: true, digits: <$T>::MANTISSA_DIGITS, max_exp: <$T>::MAX_EXP as u32 }; } )*
+    };
+}
+
+impl_has_repr!(unsigned u8, u16, u32, u64, usize, u128);
+impl_has_repr!(signed i8, i16, i32, i64, isize, i128);
+impl_has_repr!(float f32, f64);
+
+// `char::MAX` is less than 2^21, and only the code point is ever cast.
+impl HasRepr for char {
+    const REPR: Repr = Repr { float: false, signed: false, digits: 21, max_exp: 21 };
+}
+
+impl HasRepr for bool {
+    const REPR: Repr = Repr { float: false, signed: false, digits: 1, max_exp: 1 };
+}
+
+const fn may_truncate(t: Repr, u: Repr) -> bool {
+    match (t.float, u.float) {
+        (true, false) => true,
+        (true, true) => t.max_exp > u.max_exp,
+        // The largest integer below `2^max_exp` rounds up to infinity.
+        (false, true) => t.max_exp >= u.max_exp,
+        (false, false) => t.max_exp > u.max_exp || (t.signed && !u.signed),
+    }
+}
+
+const fn may_lose_precision(t: Repr, u: Repr) -> bool {
+    (t.float && !u.float) || (u.float && t.digits > u.digits)
+}
+
+const fn may_change_sign(t: Repr, u: Repr) -> bool {
+    // Negative values become 0 or wrap, and integers can wrap into the sign
+    // bit. Float targets and saturating float sources keep the sign.
+    (t.signed && !u.signed) || (!t.float && !u.float && u.signed && t.max_exp > u.max_exp)
+}
+
+macro_rules! impl_conversion_kind {
+    ($U: ty => $( $T: ty ),* ) => {
+        $(
+        impl ConversionKind<$U> for $T {
+            const LOSSLESS: bool = !(may_truncate(<$U>::REPR, <$T>::REPR)
+                || may_lose_precision(<$U>::REPR, <$T>::REPR)
+                || may_change_sign(<$U>::REPR, <$T>::REPR));
+            const MAY_TRUNCATE: bool = may_truncate(<$U>::REPR, <$T>::REPR);
+            const MAY_LOSE_PRECISION: bool = may_lose_precision(<$U>::REPR, <$T>::REPR);
+            const MAY_CHANGE_SIGN: bool = may_change_sign(<$U>::REPR, <$T>::REPR);
+        }
+        )*
+    };
+}
+
+impl_conversion_kind!(u8 => char, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_conversion_kind!(i8 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_conversion_kind!(u16 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_conversion_kind!(i16 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_conversion_kind!(u32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_conversion_kind!(i32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_conversion_kind!(u64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_conversion_kind!(i64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_conversion_kind!(usize => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_conversion_kind!(isize => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_conversion_kind!(u128 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_conversion_kind!(i128 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_conversion_kind!(f32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_conversion_kind!(f64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_conversion_kind!(char => char, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_conversion_kind!(bool => bool, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+
+
+#[test]
+fn conversion_kind() {
+    fn kind<T, U: ConversionKind<T>>() -> [bool; 4] {
+        [U::LOSSLESS, U::MAY_TRUNCATE, U::MAY_LOSE_PRECISION, U::MAY_CHANGE_SIGN]
+    }
+    const LOSSLESS: [bool; 4] = [true, false, false, false];
+
+    assert_eq!(kind::<u8, u8>(), LOSSLESS);
+    assert_eq!(kind::<u8, char>(), LOSSLESS);
+    assert_eq!(kind::<u16, f32>(), LOSSLESS);
+    assert_eq!(kind::<i32, f64>(), LOSSLESS);
+    assert_eq!(kind::<f32, f64>(), LOSSLESS);
+    assert_eq!(kind::<char, f32>(), LOSSLESS);
+    assert_eq!(kind::<bool, i8>(), LOSSLESS);
+    assert_eq!(kind::<u32, f32>(), [false, false, true, false]);
+    assert_eq!(kind::<u128, f32>(), [false, true, true, false]);
+    assert_eq!(kind::<i128, f32>(), [false, false, true, false]);
+    assert_eq!(kind::<f64, f32>(), [false, true, true, false]);
+    assert_eq!(kind::<f32, i32>(), [false, true, true, false]);
+    assert_eq!(kind::<f32, u8>(), [false, true, true, true]);
+    assert_eq!(kind::<u8, i8>(), [false, true, false, true]);
+    assert_eq!(kind::<i8, u128>(), [false, true, false, true]);
+    assert_eq!(kind::<u64, u32>(), [false, true, false, false]);
+    assert_eq!(kind::<i64, i32>(), [false, true, false, true]);
+    assert_eq!(kind::<char, u16>(), [false, true, false, false]);
+    assert_eq!(kind::<char, i32>(), LOSSLESS);
+
+    #[cfg(target_pointer_width = "64")]
+    {
+        assert_eq!(kind::<usize, u64>(), LOSSLESS);
+        assert_eq!(kind::<u64, usize>(), LOSSLESS);
+        assert_eq!(kind::<isize, i64>(), LOSSLESS);
+        assert_eq!(kind::<usize, u32>(), [false, true, false, false]);
+    }
+    #[cfg(target_pointer_width = "32")]
+    {
+        assert_eq!(kind::<usize, u32>(), LOSSLESS);
+        assert_eq!(kind::<u64, usize>(), [false, true, false, false]);
+    }
+}
+
+// Every flag that is not set must hold for the edge values of the source.
+#[test]
+fn conversion_kind_matches_values() {
+    use crate::{CheckedPrimitiveFrom, ExactPrimitiveFrom};
+
+    macro_rules! check {
+        ($U: ty: $values: tt => $( $T: ty ),* ) => {
+            $(
+            for &a in &$values {
+                let a: $U = a;
+                let b = <$T as PrimitiveFrom<$U>>::from(a);
+                let exact = <$T as ExactPrimitiveFrom<$U>>::exact_from(a);
+                let name = concat!(stringify!($U), " -> ", stringify!($T));
+                if !<$T as ConversionKind<$U>>::MAY_TRUNCATE {
+                    assert!(<$T as CheckedPrimitiveFrom<$U>>::checked_from(a).is_some(), "{} truncates {:?}", name, a);
+                }
+                if !<$T as ConversionKind<$U>>::MAY_CHANGE_SIGN {
+                    let (x, y): (f64, f64) = (PrimitiveFrom::from(a), PrimitiveFrom::from(b));
+                    let flipped = (x < 0.0 && y > 0.0) || (x > 0.0 && y < 0.0);
+                    assert!(!flipped, "{} changes the sign of {:?}", name, a);
+                }
+                if <$T as ConversionKind<$U>>::LOSSLESS {
+                    assert!(exact.is_ok(), "{} is lossy for {:?}", name, a);
+                }
+                if !<$T as ConversionKind<$U>>::MAY_LOSE_PRECISION {
+                    assert!(!matches!(exact, Err(crate::TryPrimitiveFromError::PrecisionLoss { .. })), "{} loses precision of {:?}", name, a);
+                }
+            }
+            )*
+        };
+    }
+    macro_rules! check_all {
+        ($( $U: ty: $values: tt ),* ) => {
+            $(
+            check!($U: $values => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+            )*
+        };
+    }
+
+    check_all!(
+        u8: [0, 1, 127, 128, u8::MAX],
+        u16: [0, 255, 256, u16::MAX],
+        u32: [0, u16::MAX as u32 + 1, (1 << 24) + 1, u32::MAX],
+        u64: [0, (1 << 53) + 1, u64::MAX],
+        usize: [0, usize::MAX >> 1, usize::MAX],
+        u128: [0, u64::MAX as u128 + 1, u128::MAX],
+        i8: [0, -1, i8::MIN, i8::MAX],
+        i16: [0, -1, -129, 128, i16::MIN, i16::MAX],
+        i32: [0, -1, (1 << 24) + 1, i32::MIN, i32::MAX],
+        i64: [0, -1, (1 << 53) + 1, i64::MIN, i64::MAX],
+        isize: [0, -1, isize::MIN, isize::MAX],
+        i128: [0, -1, i128::MIN, i128::MAX],
+        f32: [0.0, -1.0, 0.5, -0.5, 256.0, 1e20, f32::MAX, f32::MIN, f32::INFINITY, f32::NAN],
+        f64: [0.0, -1.0, 0.1, 1e20, 1e300, f64::MIN, f64::NEG_INFINITY, f64::NAN],
+        char: ['\0', 'A', '\u{FF}', '\u{100}', '\u{FFFF}', char::MAX],
+        bool: [false, true]
+    );
+    check!(u8: [0, 65, u8::MAX] => char);
+    check!(char: ['\0', char::MAX] => char);
+    check!(bool: [false, true] => bool);
+}
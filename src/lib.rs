@@ -1,3 +1,43 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![cfg_attr(feature = "nightly", feature(const_trait_impl))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod checked;
+pub use checked::CheckedPrimitiveFrom;
+mod saturating;
+pub use saturating::SaturatingPrimitiveFrom;
+mod wrapping;
+pub use wrapping::WrappingPrimitiveFrom;
+mod try_from;
+pub use try_from::{TryPrimitiveFrom, TryPrimitiveFromError};
+mod exact;
+pub use exact::ExactPrimitiveFrom;
+pub mod rounding;
+pub use rounding::RoundingPrimitiveFrom;
+mod normalized;
+pub use normalized::NormalizedFrom;
+pub mod audio;
+pub mod endian;
+pub use endian::{ReadPrimitiveFrom, WritePrimitiveInto};
+mod kind;
+pub use kind::ConversionKind;
+mod lossless;
+pub use lossless::LosslessPrimitiveFrom;
+mod cast;
+pub use cast::Cast;
+mod compound;
+mod num_wrappers;
+mod nonzero;
+mod slice;
+pub use slice::{convert_slice, PrimitiveFromSlice};
+#[cfg(feature = "alloc")]
+pub use slice::convert_vec;
+#[cfg(feature = "nightly")]
+pub use cast::nightly::ConstPrimitiveFrom;
+#[cfg(feature = "derive")]
+pub use primitive_from_derive::PrimitiveFrom;
 
 /// A generic interface for casting between machine scalars with the
 /// `as` operator, which admits narrowing and precision loss.
@@ -5,64 +45,195 @@
 /// numeric type (e.g. a newtype around another primitive), and the
 /// intended conversion must never fail.
 ///
+/// Arrays and tuples of up to 12 elements are converted element by element,
+/// as are their [`CheckedPrimitiveFrom`] and [`SaturatingPrimitiveFrom`]
+/// versions. [`Wrapping`](core::num::Wrapping) and
+/// [`Saturating`](core::num::Saturating) convert through their inner value,
+/// with the arithmetic of the target: converting into a `Wrapping` uses
+/// [`WrappingPrimitiveFrom`], and into a `Saturating` uses
+/// [`SaturatingPrimitiveFrom`]. `NonZero` integers convert like their
+/// value, while converting into them is only offered by
+/// [`CheckedPrimitiveFrom`] and [`TryPrimitiveFrom`].
+///
 /// # Examples
 ///
 /// ```
 /// # use primitive_from::PrimitiveFrom;
 /// let three: i32 = PrimitiveFrom::from(3.14159265f32);
 /// assert_eq!(three, 3);
+///
+/// let point: [f32; 3] = PrimitiveFrom::from([1.0f64, 2.0, 3.0]);
+/// assert_eq!(point, [1.0, 2.0, 3.0]);
+/// let size: (f32, f32) = PrimitiveFrom::from((640i32, 480i32));
+/// assert_eq!(size, (640.0, 480.0));
+///
+/// use core::num::{Saturating, Wrapping};
+/// let hash: Wrapping<u8> = PrimitiveFrom::from(Wrapping(0x1234u32));
+/// assert_eq!(hash, Wrapping(0x34));
+/// let level: Saturating<u8> = PrimitiveFrom::from(Wrapping(0x1234u32));
+/// assert_eq!(level, Saturating(u8::MAX));
 /// ```
-/// 
-/// # Safety
-/// 
-/// Currently, some uses of the `as` operator are not entirely safe.
-/// In particular, it is undefined behavior if:
-/// 
-/// - A truncated floating point value cannot fit in the target integer
-///   type ([#10184](https://github.com/rust-lang/rust/issues/10184));
-/// 
-/// ```ignore
-/// # use primitive_from::PrimitiveFrom;
-/// let x: u8 = PrimitiveFrom::from(1.04E+17); // UB
-/// ```
-/// 
-/// - Or a floating point value does not fit in another floating
-///   point type ([#15536](https://github.com/rust-lang/rust/issues/15536)).
 ///
-/// ```ignore
+/// # Out of range floats
+///
+/// Since Rust 1.45 float casts with `as` are fully defined, and never
+/// undefined behavior:
+///
+/// - A float cast to an integer is truncated toward zero and saturates to
+///   the bounds of the target, with NaN mapped to 0.
+/// - A `f64` too large for `f32` becomes infinite.
+///
+/// ```
 /// # use primitive_from::PrimitiveFrom;
-/// let x: f32 = PrimitiveFrom::from(1e300f64); // UB
+/// let x: u8 = PrimitiveFrom::from(1.04E+17);
+/// assert_eq!(x, u8::MAX);
+/// let x: i32 = PrimitiveFrom::from(f64::NAN);
+/// assert_eq!(x, 0);
+/// let x: f32 = PrimitiveFrom::from(1e300f64);
+/// assert_eq!(x, f32::INFINITY);
 /// ```
-/// 
 pub trait PrimitiveFrom<T>
 {
     fn from(_:T) -> Self;
 }
 
+/// The counterpart to [`PrimitiveFrom`], in the same way that `Into` is the
+/// counterpart to `From`. It is implemented for every `T` where
+/// `U: PrimitiveFrom<T>`, so it should never be implemented directly.
+///
+/// # Examples
+///
+/// ```
+/// # use primitive_from::PrimitiveInto;
+/// let x: u8 = 300u16.primitive_into();
+/// assert_eq!(x, 44);
+///
+/// fn halve<T: PrimitiveInto<f64>>(a: T) -> f64 {
+///     a.primitive_into() / 2.0
+/// }
+/// assert_eq!(halve(3u8), 1.5);
+/// ```
+pub trait PrimitiveInto<U>
+{
+    fn primitive_into(self) -> U;
+}
+
+impl<T, U: PrimitiveFrom<T>> PrimitiveInto<U> for T {
+    #[inline] fn primitive_into(self) -> U { U::from(self) }
+}
+
+/// Implements [`PrimitiveFrom`] for rows of conversions, with an expression
+/// of your choice instead of `as`. This is how the crate implements its own
+/// conversions, and it can be used for newtypes and custom numeric types.
+///
+/// Each row lists a source type, the target types, and closure-like
+/// expressions that convert a value of the source type to each of the
+/// targets. The same expression is used for every target in a row, so it
+/// can rely on inference of the target type. Optionally, `checked` and
+/// `saturating` expressions also implement [`CheckedPrimitiveFrom`] and
+/// [`SaturatingPrimitiveFrom`] for the same pairs. [`PrimitiveInto`] comes
+/// for free, since it is implemented for every [`PrimitiveFrom`].
+///
+/// Rows of the form `impl_primitive_from!(u8 => u16, u32)` convert with `as`,
+/// which only works between primitives.
+///
+/// # Examples
+///
+/// ```
+/// use primitive_from::{impl_primitive_from, CheckedPrimitiveFrom, PrimitiveFrom, PrimitiveInto, SaturatingPrimitiveFrom};
+///
+/// /// A 8.8 fixed point number.
+/// #[derive(Debug, PartialEq)]
+/// struct Q8(i16);
+///
+/// impl_primitive_from! {
+///     i8 => Q8 {
+///         from: |a| Q8((a as i16) << 8),
+///     }
+///     f32 => Q8 {
+///         from: |a| Q8(PrimitiveFrom::from(a * 256.0)),
+///         checked: |a| CheckedPrimitiveFrom::checked_from(a * 256.0).map(Q8),
+///         saturating: |a| Q8(SaturatingPrimitiveFrom::saturating_from(a * 256.0)),
+///     }
+///     Q8 => f32, f64 {
+///         from: |a| PrimitiveFrom::from(a.0 as f32 / 256.0),
+///     }
+/// }
+///
+/// assert_eq!(<Q8 as PrimitiveFrom<i8>>::from(-2), Q8(-512));
+/// assert_eq!(<Q8 as CheckedPrimitiveFrom<f32>>::checked_from(1.5), Some(Q8(384)));
+/// assert_eq!(<Q8 as CheckedPrimitiveFrom<f32>>::checked_from(200.0), None);
+/// assert_eq!(<Q8 as SaturatingPrimitiveFrom<f32>>::saturating_from(200.0), Q8(i16::MAX));
+/// let x: f64 = Q8(-640).primitive_into();
+/// assert_eq!(x, -2.5);
+/// ```
+#[macro_export]
 macro_rules! impl_primitive_from {
+    (@one $U: ty => $T: ty {
+        from: |$a: ident| $from: expr
+        $(, checked: |$c: ident| $checked: expr)?
+        $(, saturating: |$s: ident| $saturating: expr)?
+        $(,)?
+    }) => {
+        impl $crate::PrimitiveFrom<$U> for $T {
+            #[inline] fn from($a:$U) -> $T { $from }
+        }
+        $(
+        impl $crate::CheckedPrimitiveFrom<$U> for $T {
+            #[inline] fn checked_from($c:$U) -> Option<$T> { $checked }
+        }
+        )?
+        $(
+        impl $crate::SaturatingPrimitiveFrom<$U> for $T {
+            #[inline] fn saturating_from($s:$U) -> $T { $saturating }
+        }
+        )?
+    };
     ($U: ty => $( $T: ty ),* ) => {
         $(
-        impl PrimitiveFrom<$U> for $T {
+        impl $crate::PrimitiveFrom<$U> for $T {
             #[inline] fn from(a:$U) -> $T { a as $T }
         }
         )*
     };
+    (@row $U: ty => [ $( $T: ty ),+ ] $body: tt) => {
+        $(
+        $crate::impl_primitive_from!(@one $U => $T $body);
+        )+
+    };
+    ($( $U: ty => $( $T: ty ),+ { $( $body: tt )* } )*) => {
+        $(
+        $crate::impl_primitive_from!(@row $U => [ $( $T ),+ ] { $( $body )* });
+        )*
+    };
 }
 
-impl_primitive_from!(u8 => char, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, f32, f64);
-impl_primitive_from!(i8 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, f32, f64);
-impl_primitive_from!(u16 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, f32, f64);
-impl_primitive_from!(i16 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, f32, f64);
-impl_primitive_from!(u32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, f32, f64);
-impl_primitive_from!(i32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, f32, f64);
-impl_primitive_from!(u64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, f32, f64);
-impl_primitive_from!(i64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, f32, f64);
-impl_primitive_from!(usize => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, f32, f64);
-impl_primitive_from!(isize => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, f32, f64);
-impl_primitive_from!(f32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, f32, f64);
-impl_primitive_from!(f64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, f32, f64);
-impl_primitive_from!(char => char, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64);
-impl_primitive_from!(bool => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64);
+impl_primitive_from!(u8 => char, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from!(i8 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from!(u16 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from!(i16 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from!(u32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from!(i32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from!(u64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from!(i64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from!(usize => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from!(isize => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from!(u128 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from!(i128 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from!(f32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from!(f64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from!(char => char, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_primitive_from!(bool => bool, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+
+// `as` doesn't allow these, but they can never fail or lose precision.
+impl_primitive_from! {
+    char => f32, f64 {
+        from: |a| PrimitiveFrom::from(a as u32),
+    }
+    bool => f32, f64 {
+        from: |a| PrimitiveFrom::from(a as u8),
+    }
+}
 
 
 #[test]
@@ -70,9 +241,88 @@ fn as_primitive() {
     let x: f32 = PrimitiveFrom::from(1.625f64);
     assert_eq!(x, 1.625f32);
 
-    let x: f32 = PrimitiveFrom::from(3.14159265358979323846f64);
-    assert_eq!(x, 3.1415927f32);
+    let x: f32 = PrimitiveFrom::from(core::f64::consts::PI);
+    assert_eq!(x, core::f32::consts::PI);
 
     let x: u8 = PrimitiveFrom::from(768i16);
     assert_eq!(x, 0);
 }
+
+#[test]
+fn as_primitive_128() {
+    let x: u8 = PrimitiveFrom::from(u128::MAX);
+    assert_eq!(x, u8::MAX);
+
+    let x: i64 = PrimitiveFrom::from((1u128 << 64) + 5);
+    assert_eq!(x, 5);
+
+    let x: u128 = PrimitiveFrom::from(-1i8);
+    assert_eq!(x, u128::MAX);
+
+    let x: i128 = PrimitiveFrom::from(u128::MAX);
+    assert_eq!(x, -1);
+
+    let x: u128 = PrimitiveFrom::from(i128::MIN);
+    assert_eq!(x, 1 << 127);
+
+    let x: f32 = PrimitiveFrom::from(u128::MAX);
+    assert_eq!(x, f32::INFINITY);
+
+    let x: u128 = PrimitiveFrom::from(-1.5f64);
+    assert_eq!(x, 0);
+
+    let x: i128 = PrimitiveFrom::from(1e40f64);
+    assert_eq!(x, i128::MAX);
+
+    let x: u128 = PrimitiveFrom::from('\u{10FFFF}');
+    assert_eq!(x, 0x10FFFF);
+
+    let x: i128 = PrimitiveFrom::from(true);
+    assert_eq!(x, 1);
+}
+
+#[cfg(test)]
+macro_rules! test_as_matrix {
+    ($U: ty: [ $( $a: expr ),* ] => $( $T: ty ),* ) => {
+        for &a in [ $( $a ),* ].iter() {
+            let a: $U = a;
+            $(
+            let x: $T = PrimitiveFrom::from(a);
+            // Compared through `Debug` so that NaN matches NaN.
+            assert_eq!(format!("{:?}", x), format!("{:?}", a as $T), "{:?} as {}", a, stringify!($T));
+            let y: $T = a.primitive_into();
+            assert_eq!(format!("{:?}", y), format!("{:?}", x), "{:?}.primitive_into() as {}", a, stringify!($T));
+            )*
+        }
+    };
+}
+
+#[test]
+fn as_matrix() {
+    test_as_matrix!(u8: [0, 1, 127, 128, 255] => char, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_as_matrix!(i8: [0, 1, -1, i8::MIN, i8::MAX] => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_as_matrix!(u16: [0, 1, 255, 256, u16::MAX] => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_as_matrix!(i16: [0, -1, -129, i16::MIN, i16::MAX] => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_as_matrix!(u32: [0, 16777217, 0xD800, u32::MAX] => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_as_matrix!(i32: [0, -1, -16777217, i32::MIN, i32::MAX] => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_as_matrix!(u64: [0, (1 << 53) + 1, u64::MAX] => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_as_matrix!(i64: [0, -1, i64::MIN, i64::MAX] => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_as_matrix!(usize: [0, usize::MAX] => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_as_matrix!(isize: [0, -1, isize::MIN, isize::MAX] => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_as_matrix!(u128: [0, 1 << 64, u128::MAX] => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_as_matrix!(i128: [0, -1, i128::MIN, i128::MAX] => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_as_matrix!(f32: [0.0, -0.0, 0.5, -1.5, 255.9, 1e10, -1e30, f32::MAX, f32::MIN_POSITIVE, f32::INFINITY, f32::NEG_INFINITY, f32::NAN]
+        => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_as_matrix!(f64: [0.0, -0.0, 0.5, -1.5, 255.9, 1e10, -1e30, 1e300, f64::MIN_POSITIVE, f64::INFINITY, f64::NEG_INFINITY, f64::NAN]
+        => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+    test_as_matrix!(char: ['\0', 'A', '\u{FF}', '\u{100}', '\u{10FFFF}'] => char, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+    test_as_matrix!(bool: [false, true] => bool, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+
+    // Not allowed by `as`.
+    for &a in ['\0', 'A', '\u{10FFFF}'].iter() {
+        assert_eq!(<f32 as PrimitiveFrom<char>>::from(a), a as u32 as f32);
+        assert_eq!(<f64 as PrimitiveFrom<char>>::from(a), a as u32 as f64);
+    }
+    assert_eq!(<f32 as PrimitiveFrom<bool>>::from(true), 1.0);
+    assert_eq!(<f64 as PrimitiveFrom<bool>>::from(false), 0.0);
+}
@@ -0,0 +1,110 @@
+use crate::{ConversionKind, PrimitiveFrom};
+
+/// A marker for [`PrimitiveFrom`] casts where every source value is
+/// represented exactly in the target, so the cast never wraps, saturates or
+/// rounds.
+///
+/// On top of the pairs covered by `From`, it includes integers to floats with
+/// enough mantissa digits like `u16 -> f32` and `i32 -> f64`, `char` to any
+/// type that holds its code points, and `usize`/`isize` to fixed size types
+/// for the pointer width of the target, like `usize -> u64` on 64-bit
+/// targets. Every impl agrees with [`ConversionKind::LOSSLESS`].
+///
+/// # Examples
+///
+/// ```
+/// # use primitive_from::LosslessPrimitiveFrom;
+/// fn mean<T: Copy, U: LosslessPrimitiveFrom<T> + Into<f64>>(values: &[T]) -> f64 {
+///     values.iter().map(|&v| U::from(v).into()).sum::<f64>() / values.len() as f64
+/// }
+/// assert_eq!(mean::<u16, f32>(&[1, 2, 6]), 3.0);
+/// ```
+///
+/// A cast that may lose information does not compile:
+///
+/// ```compile_fail
+/// # use primitive_from::LosslessPrimitiveFrom;
+/// fn widen<T, U: LosslessPrimitiveFrom<T>>(a: T) -> U { U::from(a) }
+/// let x: f32 = widen(16777217u32);
+/// ```
+pub trait LosslessPrimitiveFrom<T>: PrimitiveFrom<T>
+{
+}
+
+macro_rules! impl_lossless_primitive_from {
+    ($U: ty => $( $T: ty ),* ) => {
+        $(
+        impl LosslessPrimitiveFrom<$U> for $T {}
+        const _: () = assert!(<$T as ConversionKind<$U>>::LOSSLESS);
+        )*
+    };
+}
+
+impl_lossless_primitive_from!(u8 => char, u8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_lossless_primitive_from!(i8 => i8, i16, i32, isize, i64, i128, f32, f64);
+impl_lossless_primitive_from!(u16 => u16, u32, i32, u64, usize, i64, u128, i128, f32, f64);
+impl_lossless_primitive_from!(i16 => i16, i32, isize, i64, i128, f32, f64);
+impl_lossless_primitive_from!(u32 => u32, u64, i64, u128, i128, f64);
+impl_lossless_primitive_from!(i32 => i32, i64, i128, f64);
+impl_lossless_primitive_from!(u64 => u64, u128, i128);
+impl_lossless_primitive_from!(i64 => i64, i128);
+impl_lossless_primitive_from!(usize => usize, u128, i128);
+impl_lossless_primitive_from!(isize => isize, i128);
+impl_lossless_primitive_from!(u128 => u128);
+impl_lossless_primitive_from!(i128 => i128);
+impl_lossless_primitive_from!(f32 => f32, f64);
+impl_lossless_primitive_from!(f64 => f64);
+impl_lossless_primitive_from!(char => char, u32, i32, u64, i64, u128, i128, f32, f64);
+impl_lossless_primitive_from!(bool => bool, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+
+#[cfg(target_pointer_width = "16")]
+mod pointer_width {
+    use super::*;
+    impl_lossless_primitive_from!(usize => u16, u32, i32, u64, i64, f32, f64);
+    impl_lossless_primitive_from!(isize => i16, i32, i64, f32, f64);
+}
+
+#[cfg(target_pointer_width = "32")]
+mod pointer_width {
+    use super::*;
+    impl_lossless_primitive_from!(usize => u32, u64, i64, f64);
+    impl_lossless_primitive_from!(isize => i32, i64, f64);
+    impl_lossless_primitive_from!(u16 => isize);
+    impl_lossless_primitive_from!(u32 => usize);
+    impl_lossless_primitive_from!(i32 => isize);
+    impl_lossless_primitive_from!(char => usize, isize);
+}
+
+#[cfg(target_pointer_width = "64")]
+mod pointer_width {
+    use super::*;
+    impl_lossless_primitive_from!(usize => u64);
+    impl_lossless_primitive_from!(isize => i64);
+    impl_lossless_primitive_from!(u16 => isize);
+    impl_lossless_primitive_from!(u32 => usize, isize);
+    impl_lossless_primitive_from!(i32 => isize);
+    impl_lossless_primitive_from!(u64 => usize);
+    impl_lossless_primitive_from!(i64 => isize);
+    impl_lossless_primitive_from!(char => usize, isize);
+}
+
+
+#[test]
+fn lossless_primitive_from() {
+    fn widen<T, U: LosslessPrimitiveFrom<T>>(a: T) -> U {
+        U::from(a)
+    }
+
+    assert_eq!(widen::<u16, f32>(u16::MAX), 65535.0);
+    assert_eq!(widen::<i32, f64>(i32::MIN), -2147483648.0);
+    assert_eq!(widen::<char, f32>(char::MAX), 1114111.0);
+    assert_eq!(widen::<u8, char>(b'a'), 'a');
+    assert_eq!(widen::<bool, i8>(true), 1);
+    assert_eq!(widen::<usize, u128>(usize::MAX), usize::MAX as u128);
+    #[cfg(target_pointer_width = "64")]
+    {
+        assert_eq!(widen::<usize, u64>(usize::MAX), u64::MAX);
+        assert_eq!(widen::<u64, usize>(u64::MAX), usize::MAX);
+        assert_eq!(widen::<i64, isize>(i64::MIN), isize::MIN);
+    }
+}
@@ -0,0 +1,110 @@
+//! Conversions to and from the `core::num::NonZero*` integers.
+//!
+//! Converting out of a `NonZero` integer behaves like converting its value.
+//! Converting into one is only offered by [`CheckedPrimitiveFrom`] and
+//! [`TryPrimitiveFrom`], which fail when the value is out of range, or
+//! when it converts to zero (like `0.5 -> NonZeroU8`).
+
+use crate::{CheckedPrimitiveFrom, PrimitiveFrom, TryPrimitiveFrom, TryPrimitiveFromError};
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128, NonZeroU16, NonZeroU32,
+    NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+macro_rules! impl_nonzero_primitive_from {
+    (nonzero $( $N: ident : $I: ty ),* ) => {
+        $(
+        impl<U> CheckedPrimitiveFrom<U> for $N where $I: CheckedPrimitiveFrom<U> {
+            #[inline] fn checked_from(a:U) -> Option<$N> { $N::new(<$I as CheckedPrimitiveFrom<U>>::checked_from(a)?) }
+        }
+
+        impl<U: Copy> TryPrimitiveFrom<U> for $N where $I: TryPrimitiveFrom<U> {
+            #[inline] fn try_from(a:U) -> Result<$N, TryPrimitiveFromError<U>> {
+                let target = stringify!($N);
+                let b = <$I as TryPrimitiveFrom<U>>::try_from(a).map_err(|e| e.replace(a, target))?;
+                $N::new(b).ok_or(TryPrimitiveFromError::Zero { value: a, target })
+            }
+        }
+        )*
+    };
+    (into $N: ident : $I: ty => $( $T: ty ),* ) => {
+        $(
+        impl PrimitiveFrom<$N> for $T {
+            #[inline] fn from(a:$N) -> $T { <$T as PrimitiveFrom<$I>>::from(a.get()) }
+        }
+        )*
+        impl_nonzero_primitive_from!(checked_into $N: $I => $($T),*);
+    };
+    (checked_into $N: ident : $I: ty => $( $T: ty ),* ) => {
+        $(
+        impl CheckedPrimitiveFrom<$N> for $T {
+            #[inline] fn checked_from(a:$N) -> Option<$T> { <$T as CheckedPrimitiveFrom<$I>>::checked_from(a.get()) }
+        }
+
+        impl TryPrimitiveFrom<$N> for $T {
+            #[inline] fn try_from(a:$N) -> Result<$T, TryPrimitiveFromError<$N>> {
+                <$T as TryPrimitiveFrom<$I>>::try_from(a.get()).map_err(|e| e.replace(a, stringify!($T)))
+            }
+        }
+        )*
+    };
+}
+
+impl_nonzero_primitive_from!(nonzero NonZeroU8: u8, NonZeroI8: i8, NonZeroU16: u16, NonZeroI16: i16, NonZeroU32: u32, NonZeroI32: i32,
+    NonZeroU64: u64, NonZeroI64: i64, NonZeroUsize: usize, NonZeroIsize: isize, NonZeroU128: u128, NonZeroI128: i128);
+impl_nonzero_primitive_from!(into NonZeroU8: u8 => char, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_nonzero_primitive_from!(into NonZeroI8: i8 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_nonzero_primitive_from!(into NonZeroU16: u16 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_nonzero_primitive_from!(into NonZeroI16: i16 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_nonzero_primitive_from!(into NonZeroU32: u32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_nonzero_primitive_from!(into NonZeroI32: i32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_nonzero_primitive_from!(into NonZeroU64: u64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_nonzero_primitive_from!(into NonZeroI64: i64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_nonzero_primitive_from!(into NonZeroUsize: usize => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_nonzero_primitive_from!(into NonZeroIsize: isize => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_nonzero_primitive_from!(into NonZeroU128: u128 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_nonzero_primitive_from!(into NonZeroI128: i128 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_nonzero_primitive_from!(checked_into NonZeroU8: u8 => bool);
+impl_nonzero_primitive_from!(checked_into NonZeroI8: i8 => char, bool);
+impl_nonzero_primitive_from!(checked_into NonZeroU16: u16 => char, bool);
+impl_nonzero_primitive_from!(checked_into NonZeroI16: i16 => char, bool);
+impl_nonzero_primitive_from!(checked_into NonZeroU32: u32 => char, bool);
+impl_nonzero_primitive_from!(checked_into NonZeroI32: i32 => char, bool);
+impl_nonzero_primitive_from!(checked_into NonZeroU64: u64 => char, bool);
+impl_nonzero_primitive_from!(checked_into NonZeroI64: i64 => char, bool);
+impl_nonzero_primitive_from!(checked_into NonZeroUsize: usize => char, bool);
+impl_nonzero_primitive_from!(checked_into NonZeroIsize: isize => char, bool);
+impl_nonzero_primitive_from!(checked_into NonZeroU128: u128 => char, bool);
+impl_nonzero_primitive_from!(checked_into NonZeroI128: i128 => char, bool);
+
+
+#[test]
+fn nonzero_primitive_from() {
+    use TryPrimitiveFromError::*;
+
+    let id = NonZeroU32::new(300).unwrap();
+    assert_eq!(<u8 as PrimitiveFrom<NonZeroU32>>::from(id), 44);
+    assert_eq!(<f64 as PrimitiveFrom<NonZeroU32>>::from(id), 300.0);
+    assert_eq!(<i128 as PrimitiveFrom<NonZeroI8>>::from(NonZeroI8::new(-1).unwrap()), -1);
+    assert_eq!(<char as PrimitiveFrom<NonZeroU8>>::from(NonZeroU8::new(b'A').unwrap()), 'A');
+    assert_eq!(<u8 as CheckedPrimitiveFrom<NonZeroU32>>::checked_from(id), None);
+    assert_eq!(<u8 as TryPrimitiveFrom<NonZeroU32>>::try_from(id), Err(Overflow { value: id, target: "u8" }));
+    assert_eq!(<bool as CheckedPrimitiveFrom<NonZeroI64>>::checked_from(NonZeroI64::new(1).unwrap()), Some(true));
+
+    assert_eq!(<NonZeroUsize as CheckedPrimitiveFrom<u64>>::checked_from(4096), NonZeroUsize::new(4096));
+    assert_eq!(<NonZeroUsize as CheckedPrimitiveFrom<u64>>::checked_from(0), None);
+    assert_eq!(<NonZeroU8 as CheckedPrimitiveFrom<i32>>::checked_from(256), None);
+    assert_eq!(<NonZeroI8 as CheckedPrimitiveFrom<f32>>::checked_from(-1.5), NonZeroI8::new(-1));
+    assert_eq!(<NonZeroU128 as TryPrimitiveFrom<u8>>::try_from(0), Err(Zero { value: 0, target: "NonZeroU128" }));
+    assert_eq!(<NonZeroU8 as TryPrimitiveFrom<f32>>::try_from(0.5), Err(Zero { value: 0.5, target: "NonZeroU8" }));
+    assert_eq!(<NonZeroU8 as TryPrimitiveFrom<i32>>::try_from(-3), Err(Underflow { value: -3, target: "NonZeroU8" }));
+    assert_eq!(<NonZeroI16 as TryPrimitiveFrom<f64>>::try_from(f64::NAN).unwrap_err().target(), "NonZeroI16");
+    assert_eq!(<NonZeroU32 as TryPrimitiveFrom<bool>>::try_from(true), Ok(NonZeroU32::new(1).unwrap()));
+    assert_eq!(<NonZeroU32 as TryPrimitiveFrom<char>>::try_from('\0').unwrap_err().to_string(), "\0 converts to zero, which is not a valid NonZeroU32");
+
+    let big = NonZeroU64::new(1 << 40).unwrap();
+    assert_eq!(<NonZeroU32 as CheckedPrimitiveFrom<NonZeroU64>>::checked_from(big), None);
+    assert_eq!(<NonZeroU32 as TryPrimitiveFrom<NonZeroU64>>::try_from(big), Err(Overflow { value: big, target: "NonZeroU32" }));
+    assert_eq!(<NonZeroI128 as TryPrimitiveFrom<NonZeroU8>>::try_from(NonZeroU8::new(7).unwrap()), Ok(NonZeroI128::new(7).unwrap()));
+    assert_eq!(<NonZeroU16 as CheckedPrimitiveFrom<NonZeroI32>>::checked_from(NonZeroI32::new(-7).unwrap()), None);
+}
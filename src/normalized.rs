@@ -0,0 +1,122 @@
+use crate::rounding::NearestTiesEven;
+use crate::RoundingPrimitiveFrom;
+
+/// Conversions between normalized integers and floats, as used for colors
+/// and samples on GPUs.
+///
+/// An unsigned integer (UNORM) maps `0..=MAX` onto `0.0..=1.0`, and a signed
+/// integer (SNORM) maps `-MAX..=MAX` onto `-1.0..=1.0`, with `MIN` also
+/// mapping to `-1.0`. Converting a float back follows the Direct3D and
+/// Vulkan rules:
+///
+/// - The float is clamped to `0.0..=1.0` or `-1.0..=1.0`, and NaN becomes 0.
+/// - It is scaled by `MAX` and rounded to the nearest integer, with ties to
+///   even.
+///
+/// So converting an integer to a float and back gives the same integer,
+/// except for `MIN` of a signed type which comes back as `-MAX`.
+///
+/// # Examples
+///
+/// ```
+/// # use primitive_from::NormalizedFrom;
+/// assert_eq!(<f32 as NormalizedFrom<u8>>::normalized_from(255), 1.0);
+/// assert_eq!(<f32 as NormalizedFrom<u8>>::normalized_from(51), 0.2);
+/// assert_eq!(<f32 as NormalizedFrom<i16>>::normalized_from(i16::MIN), -1.0);
+/// assert_eq!(<u8 as NormalizedFrom<f32>>::normalized_from(0.5), 128);
+/// assert_eq!(<u8 as NormalizedFrom<f32>>::normalized_from(1.5), 255);
+/// assert_eq!(<i8 as NormalizedFrom<f64>>::normalized_from(-2.0), -127);
+/// ```
+pub trait NormalizedFrom<T>
+{
+    fn normalized_from(_:T) -> Self;
+}
+
+macro_rules! impl_normalized_from {
+    (unorm $U: ty => $( $T: ty ),* ) => {
+        $(
+        impl NormalizedFrom<$U> for $T {
+            #[inline] fn normalized_from(a:$U) -> $T { a as $T / <$U>::MAX as $T }
+        }
+
+        impl NormalizedFrom<$T> for $U {
+            #[inline] fn normalized_from(a:$T) -> $U {
+                let a = if a.is_nan() { 0.0 } else { a.max(0.0).min(1.0) };
+                <$U as RoundingPrimitiveFrom<$T, NearestTiesEven>>::rounding_from(a * <$U>::MAX as $T)
+            }
+        }
+        )*
+    };
+    (snorm $U: ty => $( $T: ty ),* ) => {
+        $(
+        impl NormalizedFrom<$U> for $T {
+            #[inline] fn normalized_from(a:$U) -> $T { (a as $T / <$U>::MAX as $T).max(-1.0) }
+        }
+
+        impl NormalizedFrom<$T> for $U {
+            #[inline] fn normalized_from(a:$T) -> $U {
+                let a = if a.is_nan() { 0.0 } else { a.max(-1.0).min(1.0) };
+                // `MAX` of a wide type rounds up as a float, so -1.0 would
+                // otherwise scale to `MIN`.
+                <$U as RoundingPrimitiveFrom<$T, NearestTiesEven>>::rounding_from(a * <$U>::MAX as $T).max(-<$U>::MAX)
+            }
+        }
+        )*
+    };
+}
+
+impl_normalized_from!(unorm u8 => f32, f64);
+impl_normalized_from!(snorm i8 => f32, f64);
+impl_normalized_from!(unorm u16 => f32, f64);
+impl_normalized_from!(snorm i16 => f32, f64);
+impl_normalized_from!(unorm u32 => f32, f64);
+impl_normalized_from!(snorm i32 => f32, f64);
+impl_normalized_from!(unorm u64 => f32, f64);
+impl_normalized_from!(snorm i64 => f32, f64);
+impl_normalized_from!(unorm usize => f32, f64);
+impl_normalized_from!(snorm isize => f32, f64);
+impl_normalized_from!(unorm u128 => f32, f64);
+impl_normalized_from!(snorm i128 => f32, f64);
+
+
+#[test]
+fn normalized_round_trip() {
+    macro_rules! round_trip {
+        ($( $U: ty ),*) => {
+            $(
+            for a in <$U>::MIN..=<$U>::MAX {
+                // Signed `MIN` comes back as `-MAX`.
+                let expected = if a == <$U>::MIN && a != 0 { a + 1 } else { a };
+                let f: f32 = NormalizedFrom::normalized_from(a);
+                assert_eq!(<$U as NormalizedFrom<f32>>::normalized_from(f), expected);
+                let f: f64 = NormalizedFrom::normalized_from(a);
+                assert_eq!(<$U as NormalizedFrom<f64>>::normalized_from(f), expected);
+                assert!((-1.0..=1.0).contains(&f));
+            }
+            )*
+        };
+    }
+    round_trip!(u8, i8, u16, i16);
+}
+
+#[test]
+fn normalized_from() {
+    assert_eq!(<f32 as NormalizedFrom<u8>>::normalized_from(0), 0.0);
+    assert_eq!(<f64 as NormalizedFrom<u16>>::normalized_from(u16::MAX), 1.0);
+    assert_eq!(<f32 as NormalizedFrom<i8>>::normalized_from(i8::MIN), -1.0);
+    assert_eq!(<f32 as NormalizedFrom<i8>>::normalized_from(i8::MAX), 1.0);
+    assert_eq!(<f64 as NormalizedFrom<u128>>::normalized_from(u128::MAX), 1.0);
+
+    assert_eq!(<u8 as NormalizedFrom<f32>>::normalized_from(f32::NAN), 0);
+    assert_eq!(<u8 as NormalizedFrom<f32>>::normalized_from(-0.5), 0);
+    assert_eq!(<u8 as NormalizedFrom<f32>>::normalized_from(f32::INFINITY), 255);
+    assert_eq!(<i16 as NormalizedFrom<f32>>::normalized_from(f32::NEG_INFINITY), -i16::MAX);
+    assert_eq!(<i16 as NormalizedFrom<f64>>::normalized_from(f64::NAN), 0);
+    // 0.5 * 255 = 127.5 is a tie, rounded to even.
+    assert_eq!(<u8 as NormalizedFrom<f64>>::normalized_from(0.5), 128);
+    assert_eq!(<u8 as NormalizedFrom<f64>>::normalized_from(1.5 / 255.0), 2);
+    assert_eq!(<u8 as NormalizedFrom<f64>>::normalized_from(2.5 / 255.0), 2);
+    assert_eq!(<u32 as NormalizedFrom<f32>>::normalized_from(1.0), u32::MAX);
+    assert_eq!(<i64 as NormalizedFrom<f64>>::normalized_from(-1.0), -i64::MAX);
+    assert_eq!(<u128 as NormalizedFrom<f32>>::normalized_from(1.0), u128::MAX);
+}
@@ -0,0 +1,97 @@
+//! Conversions to and from `core::num::Wrapping` and `core::num::Saturating`.
+//!
+//! The conversion follows the target: into a `Wrapping` it is a
+//! `WrappingPrimitiveFrom`, into a `Saturating` it is a
+//! `SaturatingPrimitiveFrom`, and into a bare primitive it is a plain
+//! `PrimitiveFrom` of the inner value. Like `WrappingPrimitiveFrom`, floats
+//! don't convert into a `Wrapping`:
+//!
+//! ```compile_fail
+//! # use primitive_from::PrimitiveFrom;
+//! # use core::num::Wrapping;
+//! let w: Wrapping<u8> = PrimitiveFrom::from(300.0f32);
+//! ```
+
+use crate::{PrimitiveFrom, SaturatingPrimitiveFrom, WrappingPrimitiveFrom};
+use core::num::{Saturating, Wrapping};
+
+impl<T, U: WrappingPrimitiveFrom<T>> PrimitiveFrom<Wrapping<T>> for Wrapping<U> {
+    #[inline] fn from(a:Wrapping<T>) -> Wrapping<U> { Wrapping(U::wrapping_from(a.0)) }
+}
+
+impl<T, U: WrappingPrimitiveFrom<T>> PrimitiveFrom<Saturating<T>> for Wrapping<U> {
+    #[inline] fn from(a:Saturating<T>) -> Wrapping<U> { Wrapping(U::wrapping_from(a.0)) }
+}
+
+impl<T, U: SaturatingPrimitiveFrom<T>> PrimitiveFrom<Saturating<T>> for Saturating<U> {
+    #[inline] fn from(a:Saturating<T>) -> Saturating<U> { Saturating(U::saturating_from(a.0)) }
+}
+
+impl<T, U: SaturatingPrimitiveFrom<T>> PrimitiveFrom<Wrapping<T>> for Saturating<U> {
+    #[inline] fn from(a:Wrapping<T>) -> Saturating<U> { Saturating(U::saturating_from(a.0)) }
+}
+
+macro_rules! impl_num_wrappers_primitive_from {
+    (from $( $U: ty ),* ) => {
+        $(
+        impl<T: WrappingPrimitiveFrom<$U>> PrimitiveFrom<$U> for Wrapping<T> {
+            #[inline] fn from(a:$U) -> Wrapping<T> { Wrapping(T::wrapping_from(a)) }
+        }
+
+        impl<T: SaturatingPrimitiveFrom<$U>> PrimitiveFrom<$U> for Saturating<T> {
+            #[inline] fn from(a:$U) -> Saturating<T> { Saturating(T::saturating_from(a)) }
+        }
+        )*
+    };
+    (into $( $T: ty ),* ) => {
+        $(
+        impl<U> PrimitiveFrom<Wrapping<U>> for $T where $T: PrimitiveFrom<U> {
+            #[inline] fn from(a:Wrapping<U>) -> $T { <$T as PrimitiveFrom<U>>::from(a.0) }
+        }
+
+        impl<U> PrimitiveFrom<Saturating<U>> for $T where $T: PrimitiveFrom<U> {
+            #[inline] fn from(a:Saturating<U>) -> $T { <$T as PrimitiveFrom<U>>::from(a.0) }
+        }
+        )*
+    };
+}
+
+impl_num_wrappers_primitive_from!(from u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64, char, bool);
+impl_num_wrappers_primitive_from!(into u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64, char, bool);
+
+
+#[test]
+fn num_wrappers_primitive_from() {
+    let w: Wrapping<u8> = PrimitiveFrom::from(300i32);
+    assert_eq!(w, Wrapping(44));
+    let s: Saturating<u8> = PrimitiveFrom::from(300i32);
+    assert_eq!(s, Saturating(255));
+    let s: Saturating<i16> = PrimitiveFrom::from(-1e9f32);
+    assert_eq!(s, Saturating(i16::MIN));
+
+    let w: Wrapping<i8> = PrimitiveFrom::from(Wrapping(200u8));
+    assert_eq!(w, Wrapping(-56));
+    let s: Saturating<i8> = PrimitiveFrom::from(Saturating(200u8));
+    assert_eq!(s, Saturating(i8::MAX));
+
+    // Across wrappers the target decides.
+    let s: Saturating<i8> = PrimitiveFrom::from(Wrapping(200u8));
+    assert_eq!(s, Saturating(i8::MAX));
+    let w: Wrapping<i8> = PrimitiveFrom::from(Saturating(200u8));
+    assert_eq!(w, Wrapping(-56));
+    let w: Wrapping<u16> = PrimitiveFrom::from(Wrapping(-1i64));
+    assert_eq!(w + Wrapping(1), Wrapping(0));
+    let s: Saturating<u16> = PrimitiveFrom::from(Saturating(70000u64));
+    assert_eq!(s + Saturating(1), Saturating(u16::MAX));
+
+    let x: i8 = PrimitiveFrom::from(Saturating(200u8));
+    assert_eq!(x, -56);
+    let x: f32 = PrimitiveFrom::from(Wrapping(7u64));
+    assert_eq!(x, 7.0);
+    let c: char = PrimitiveFrom::from(Wrapping(65u8));
+    assert_eq!(c, 'A');
+    let b: Wrapping<i64> = PrimitiveFrom::from(true);
+    assert_eq!(b, Wrapping(1));
+    let w: Wrapping<u8> = PrimitiveFrom::from('\u{1F600}');
+    assert_eq!(w, Wrapping(0x00));
+}
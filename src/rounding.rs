@@ -0,0 +1,139 @@
+//! Rounding modes for [`RoundingPrimitiveFrom`].
+
+/// Round to the nearest integer, with ties rounded to the even integer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NearestTiesEven;
+
+/// Round to the nearest integer, with ties rounded away from zero. This is
+/// what `f32::round` does.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NearestTiesAway;
+
+/// Round toward negative infinity.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Floor;
+
+/// Round toward positive infinity.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Ceil;
+
+/// Round toward zero. This is what `as` does.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TowardZero;
+
+/// A version of [`PrimitiveFrom`](crate::PrimitiveFrom) for float to integer
+/// casts that rounds according to the mode `M` instead of always truncating.
+///
+/// The mode is one of the zero-sized types in [`rounding`](self). After
+/// rounding, values that are out of range saturate and NaN maps to 0, just
+/// like `as`.
+///
+/// # Examples
+///
+/// ```
+/// # use primitive_from::RoundingPrimitiveFrom;
+/// use primitive_from::rounding::{Ceil, Floor, NearestTiesAway, NearestTiesEven};
+///
+/// assert_eq!(<i32 as RoundingPrimitiveFrom<f32, Floor>>::rounding_from(-1.5), -2);
+/// assert_eq!(<i32 as RoundingPrimitiveFrom<f32, Ceil>>::rounding_from(-1.5), -1);
+/// assert_eq!(<i32 as RoundingPrimitiveFrom<f32, NearestTiesAway>>::rounding_from(2.5), 3);
+/// assert_eq!(<i32 as RoundingPrimitiveFrom<f32, NearestTiesEven>>::rounding_from(2.5), 2);
+///
+/// fn to_pixel<M, T: RoundingPrimitiveFrom<f64, M>>(x: f64) -> T {
+///     T::rounding_from(x * 255.0)
+/// }
+/// assert_eq!(to_pixel::<NearestTiesEven, u8>(0.5), 128);
+/// assert_eq!(to_pixel::<Floor, u8>(0.5), 127);
+/// ```
+pub trait RoundingPrimitiveFrom<T, M>
+{
+    fn rounding_from(_:T) -> Self;
+}
+
+// `t` is the value truncated toward zero (and saturated), and `f` is the
+// part that was dropped, which has the same sign as the source.
+macro_rules! impl_rounding_primitive_from {
+    ($M: ty, |$t: ident, $f: ident| $adjust: expr; $U: ty => $( $T: ty ),* ) => {
+        $(
+        impl RoundingPrimitiveFrom<$U, $M> for $T {
+            #[inline] fn rounding_from(a:$U) -> $T {
+                let $t = a as $T;
+                let $f = a - $t as $U;
+                $adjust
+            }
+        }
+        )*
+    };
+}
+
+macro_rules! impl_rounding_modes {
+    ($U: ty => $( $T: ty ),* ) => {
+        impl_rounding_primitive_from!(TowardZero, |t, _f| t; $U => $( $T ),*);
+        impl_rounding_primitive_from!(Floor, |t, f| if f < 0.0 { t.saturating_sub(1) } else { t }; $U => $( $T ),*);
+        impl_rounding_primitive_from!(Ceil, |t, f| if f > 0.0 { t.saturating_add(1) } else { t }; $U => $( $T ),*);
+        impl_rounding_primitive_from!(NearestTiesAway, |t, f| {
+            if f >= 0.5 {
+                t.saturating_add(1)
+            } else if f <= -0.5 {
+                t.saturating_sub(1)
+            } else {
+                t
+            }
+        }; $U => $( $T ),*);
+        impl_rounding_primitive_from!(NearestTiesEven, |t, f| {
+            let odd = t % 2 != 0;
+            if f > 0.5 || (f == 0.5 && odd) {
+                t.saturating_add(1)
+            } else if f < -0.5 || (f == -0.5 && odd) {
+                t.saturating_sub(1)
+            } else {
+                t
+            }
+        }; $U => $( $T ),*);
+    };
+}
+
+impl_rounding_modes!(f32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_rounding_modes!(f64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+
+
+#[test]
+fn rounding_primitive_from() {
+    fn round<M>(a: f64) -> [i32; 2] where i32: RoundingPrimitiveFrom<f64, M> + RoundingPrimitiveFrom<f32, M> {
+        [
+            <i32 as RoundingPrimitiveFrom<f64, M>>::rounding_from(a),
+            <i32 as RoundingPrimitiveFrom<f32, M>>::rounding_from(a as f32),
+        ]
+    }
+
+    let cases = [-2.5, -1.5, -1.2, -0.5, 0.0, 0.4, 0.5, 0.6, 1.5, 2.5, 2.7];
+    let expected = [
+        // nearest-even, nearest-away, floor, ceil, toward-zero
+        [-2, -3, -3, -2, -2],
+        [-2, -2, -2, -1, -1],
+        [-1, -1, -2, -1, -1],
+        [0, -1, -1, 0, 0],
+        [0, 0, 0, 0, 0],
+        [0, 0, 0, 1, 0],
+        [0, 1, 0, 1, 0],
+        [1, 1, 0, 1, 0],
+        [2, 2, 1, 2, 1],
+        [2, 3, 2, 3, 2],
+        [3, 3, 2, 3, 2],
+    ];
+    for (&a, e) in cases.iter().zip(expected.iter()) {
+        assert_eq!(round::<NearestTiesEven>(a), [e[0]; 2], "{}", a);
+        assert_eq!(round::<NearestTiesAway>(a), [e[1]; 2], "{}", a);
+        assert_eq!(round::<Floor>(a), [e[2]; 2], "{}", a);
+        assert_eq!(round::<Ceil>(a), [e[3]; 2], "{}", a);
+        assert_eq!(round::<TowardZero>(a), [e[4]; 2], "{}", a);
+    }
+
+    assert_eq!(<u8 as RoundingPrimitiveFrom<f32, Ceil>>::rounding_from(255.5), u8::MAX);
+    assert_eq!(<u8 as RoundingPrimitiveFrom<f32, Floor>>::rounding_from(-0.5), 0);
+    assert_eq!(<i8 as RoundingPrimitiveFrom<f64, NearestTiesAway>>::rounding_from(-1e10), i8::MIN);
+    assert_eq!(<u64 as RoundingPrimitiveFrom<f64, Ceil>>::rounding_from(f64::INFINITY), u64::MAX);
+    assert_eq!(<i64 as RoundingPrimitiveFrom<f64, NearestTiesEven>>::rounding_from(f64::NAN), 0);
+    assert_eq!(<i64 as RoundingPrimitiveFrom<f64, NearestTiesEven>>::rounding_from(4503599627370497.0), 4503599627370497);
+    assert_eq!(<u128 as RoundingPrimitiveFrom<f32, NearestTiesEven>>::rounding_from(f32::MAX), f32::MAX as u128);
+}
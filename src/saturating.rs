@@ -0,0 +1,170 @@
+use core::convert::TryFrom;
+
+/// A saturating version of [`PrimitiveFrom`](crate::PrimitiveFrom), which
+/// clamps to the bounds of the target type instead of wrapping.
+///
+/// - Integer to integer casts clamp to `MIN`/`MAX` of the target.
+/// - Float to integer casts truncate toward zero and clamp to `MIN`/`MAX`,
+///   with NaN mapped to 0. Infinities clamp like any other out-of-range
+///   value.
+/// - Integer to float casts round to nearest, and clamp to `MAX` instead of
+///   becoming infinite (only possible for `u128 -> f32`).
+/// - Float to float casts clamp finite values that are out of range to
+///   `MIN`/`MAX` of the target. Infinities stay infinite and NaN stays NaN,
+///   since both are representable in the target.
+/// - A `char` is converted through its code point, clamped to `MAX` of the
+///   target.
+///
+/// # Examples
+///
+/// ```
+/// # use primitive_from::SaturatingPrimitiveFrom;
+/// fn to_pcm<T: SaturatingPrimitiveFrom<f32>>(sample: f32) -> T {
+///     T::saturating_from(sample * 32768.0)
+/// }
+/// assert_eq!(to_pcm::<i16>(0.5), 16384);
+/// assert_eq!(to_pcm::<i16>(1.0), i16::MAX);
+/// assert_eq!(to_pcm::<i16>(-2.0), i16::MIN);
+///
+/// assert_eq!(<u8 as SaturatingPrimitiveFrom<i32>>::saturating_from(-5), 0);
+/// assert_eq!(<f32 as SaturatingPrimitiveFrom<f64>>::saturating_from(1e300), f32::MAX);
+/// ```
+pub trait SaturatingPrimitiveFrom<T>
+{
+    fn saturating_from(_:T) -> Self;
+}
+
+macro_rules! impl_saturating_primitive_from {
+    (int $U: ty => $( $T: ty ),* ) => {
+        $(
+        impl SaturatingPrimitiveFrom<$U> for $T {
+            #[inline] fn saturating_from(a:$U) -> $T {
+                match <$T>::try_from(a) {
+                    Ok(b) => b,
+                    Err(_) => if a > <$U>::default() { <$T>::MAX } else { <$T>::MIN },
+                }
+            }
+        }
+        )*
+    };
+    (int_to_float $U: ty => $( $T: ty ),* ) => {
+        $(
+        impl SaturatingPrimitiveFrom<$U> for $T {
+            #[inline] fn saturating_from(a:$U) -> $T {
+                let b = a as $T;
+                if b.is_infinite() { <$T>::MAX } else { b }
+            }
+        }
+        )*
+    };
+    (float_to_int $U: ty => $( $T: ty ),* ) => {
+        $(
+        impl SaturatingPrimitiveFrom<$U> for $T {
+            // `as` already saturates and maps NaN to 0 for float to int casts.
+            #[inline] fn saturating_from(a:$U) -> $T { a as $T }
+        }
+        )*
+    };
+    (float $U: ty => $( $T: ty ),* ) => {
+        $(
+        impl SaturatingPrimitiveFrom<$U> for $T {
+            #[inline] fn saturating_from(a:$U) -> $T {
+                let b = a as $T;
+                if b.is_infinite() && a.is_finite() {
+                    if a > 0.0 { <$T>::MAX } else { <$T>::MIN }
+                } else {
+                    b
+                }
+            }
+        }
+        )*
+    };
+    (char => $( $T: ty ),* ) => {
+        $(
+        impl SaturatingPrimitiveFrom<char> for $T {
+            #[inline] fn saturating_from(a:char) -> $T { <$T>::try_from(a as u32).unwrap_or(<$T>::MAX) }
+        }
+        )*
+    };
+    (bool => $( $T: ty ),* ) => {
+        $(
+        impl SaturatingPrimitiveFrom<bool> for $T {
+            #[inline] fn saturating_from(a:bool) -> $T { crate::PrimitiveFrom::from(a) }
+        }
+        )*
+    };
+}
+
+impl_saturating_primitive_from!(int u8 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_saturating_primitive_from!(int i8 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_saturating_primitive_from!(int u16 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_saturating_primitive_from!(int i16 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_saturating_primitive_from!(int u32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_saturating_primitive_from!(int i32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_saturating_primitive_from!(int u64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_saturating_primitive_from!(int i64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_saturating_primitive_from!(int usize => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_saturating_primitive_from!(int isize => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_saturating_primitive_from!(int u128 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_saturating_primitive_from!(int i128 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_saturating_primitive_from!(int_to_float u8 => f32, f64);
+impl_saturating_primitive_from!(int_to_float i8 => f32, f64);
+impl_saturating_primitive_from!(int_to_float u16 => f32, f64);
+impl_saturating_primitive_from!(int_to_float i16 => f32, f64);
+impl_saturating_primitive_from!(int_to_float u32 => f32, f64);
+impl_saturating_primitive_from!(int_to_float i32 => f32, f64);
+impl_saturating_primitive_from!(int_to_float u64 => f32, f64);
+impl_saturating_primitive_from!(int_to_float i64 => f32, f64);
+impl_saturating_primitive_from!(int_to_float usize => f32, f64);
+impl_saturating_primitive_from!(int_to_float isize => f32, f64);
+impl_saturating_primitive_from!(int_to_float u128 => f32, f64);
+impl_saturating_primitive_from!(int_to_float i128 => f32, f64);
+impl_saturating_primitive_from!(float_to_int f32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_saturating_primitive_from!(float_to_int f64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_saturating_primitive_from!(float f32 => f32, f64);
+impl_saturating_primitive_from!(float f64 => f32, f64);
+impl_saturating_primitive_from!(char => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_saturating_primitive_from!(bool => bool, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+
+impl SaturatingPrimitiveFrom<u8> for char {
+    #[inline] fn saturating_from(a:u8) -> char { a as char }
+}
+
+impl SaturatingPrimitiveFrom<char> for char {
+    #[inline] fn saturating_from(a:char) -> char { a }
+}
+
+impl SaturatingPrimitiveFrom<char> for f32 {
+    #[inline] fn saturating_from(a:char) -> f32 { crate::PrimitiveFrom::from(a) }
+}
+
+impl SaturatingPrimitiveFrom<char> for f64 {
+    #[inline] fn saturating_from(a:char) -> f64 { crate::PrimitiveFrom::from(a) }
+}
+
+
+#[test]
+fn saturating_primitive_from() {
+    assert_eq!(<u8 as SaturatingPrimitiveFrom<i16>>::saturating_from(768), u8::MAX);
+    assert_eq!(<u8 as SaturatingPrimitiveFrom<i16>>::saturating_from(-768), 0);
+    assert_eq!(<i8 as SaturatingPrimitiveFrom<u128>>::saturating_from(u128::MAX), i8::MAX);
+    assert_eq!(<i64 as SaturatingPrimitiveFrom<i128>>::saturating_from(i128::MIN), i64::MIN);
+    assert_eq!(<u128 as SaturatingPrimitiveFrom<isize>>::saturating_from(-1), 0);
+    assert_eq!(<i32 as SaturatingPrimitiveFrom<u32>>::saturating_from(7), 7);
+
+    assert_eq!(<u8 as SaturatingPrimitiveFrom<f32>>::saturating_from(300.0), u8::MAX);
+    assert_eq!(<u8 as SaturatingPrimitiveFrom<f32>>::saturating_from(-3.0), 0);
+    assert_eq!(<i16 as SaturatingPrimitiveFrom<f64>>::saturating_from(-1.5), -1);
+    assert_eq!(<i32 as SaturatingPrimitiveFrom<f64>>::saturating_from(f64::NAN), 0);
+    assert_eq!(<i32 as SaturatingPrimitiveFrom<f64>>::saturating_from(f64::NEG_INFINITY), i32::MIN);
+
+    assert_eq!(<f32 as SaturatingPrimitiveFrom<u128>>::saturating_from(u128::MAX), f32::MAX);
+    assert_eq!(<f32 as SaturatingPrimitiveFrom<f64>>::saturating_from(-1e300), f32::MIN);
+    assert_eq!(<f32 as SaturatingPrimitiveFrom<f64>>::saturating_from(f64::INFINITY), f32::INFINITY);
+    assert!(<f32 as SaturatingPrimitiveFrom<f64>>::saturating_from(f64::NAN).is_nan());
+
+    assert_eq!(<u8 as SaturatingPrimitiveFrom<char>>::saturating_from('\u{1F600}'), u8::MAX);
+    assert_eq!(<i8 as SaturatingPrimitiveFrom<char>>::saturating_from('A'), 65);
+    assert_eq!(<f32 as SaturatingPrimitiveFrom<char>>::saturating_from('A'), 65.0);
+    assert_eq!(<f64 as SaturatingPrimitiveFrom<bool>>::saturating_from(true), 1.0);
+}
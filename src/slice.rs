@@ -0,0 +1,165 @@
+use crate::PrimitiveFrom;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(target_arch = "x86_64")]
+mod x86_64;
+
+/// Converts whole slices with [`PrimitiveFrom`], in loops the compiler can
+/// vectorise. See [`convert_slice`] and `convert_vec`.
+///
+/// On `x86_64` the hot pairs `i16 -> f32`, `f32 -> i16` and `u8 -> f32` use
+/// explicit SSE2 code, or AVX2 code when it is available. AVX2 is detected
+/// at runtime with the `std` feature, and otherwise only used when the
+/// crate is compiled with `target-feature=+avx2`. Every path gives exactly
+/// the same results as converting each element with [`PrimitiveFrom`].
+pub trait PrimitiveFromSlice<T: Copy>: PrimitiveFrom<T> + Sized
+{
+    /// Converts every element of `src` into the same position of `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two slices have different lengths.
+    #[inline]
+    fn from_slice(src: &[T], dst: &mut [Self]) {
+        scalar(src, dst)
+    }
+
+    /// Converts every element of `src` into a new vector.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn from_vec(src: Vec<T>) -> Vec<Self> {
+        src.into_iter().map(Self::from).collect()
+    }
+}
+
+/// Converts every element of `src` into the same position of `dst`, like
+/// [`PrimitiveFrom`] would one at a time.
+///
+/// # Panics
+///
+/// Panics if the two slices have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// # use primitive_from::convert_slice;
+/// let pcm = [0i16, 16384, -32768, 32767];
+/// let mut samples = [0f32; 4];
+/// convert_slice(&pcm, &mut samples);
+/// assert_eq!(samples, [0.0, 16384.0, -32768.0, 32767.0]);
+/// ```
+#[inline]
+pub fn convert_slice<T: Copy, U: PrimitiveFromSlice<T>>(src: &[T], dst: &mut [U]) {
+    U::from_slice(src, dst)
+}
+
+/// Converts every element of `src` into a new vector, like [`PrimitiveFrom`]
+/// would one at a time. Requires the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use primitive_from::convert_vec;
+/// let bytes: Vec<u8> = convert_vec(vec![1.5f64, 300.0, -2.0]);
+/// assert_eq!(bytes, [1, 255, 0]);
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn convert_vec<T: Copy, U: PrimitiveFromSlice<T>>(src: Vec<T>) -> Vec<U> {
+    U::from_vec(src)
+}
+
+#[inline]
+fn scalar<T: Copy, U: PrimitiveFrom<T>>(src: &[T], dst: &mut [U]) {
+    assert_eq!(src.len(), dst.len(), "source and destination slices have different lengths");
+    for (d, &s) in dst.iter_mut().zip(src) {
+        *d = U::from(s);
+    }
+}
+
+macro_rules! impl_primitive_from_slice {
+    ($U: ty => $( $T: ty ),* ) => {
+        $(
+        impl PrimitiveFromSlice<$U> for $T {}
+        )*
+    };
+    // Pairs with a hand vectorised `$kernel`.
+    ($kernel: ident: $U: ty => $T: ty) => {
+        impl PrimitiveFromSlice<$U> for $T {
+            #[inline]
+            fn from_slice(src: &[$U], dst: &mut [$T]) {
+                #[cfg(target_arch = "x86_64")]
+                x86_64::$kernel(src, dst);
+                #[cfg(not(target_arch = "x86_64"))]
+                scalar(src, dst);
+            }
+
+            #[cfg(feature = "alloc")]
+            #[inline]
+            fn from_vec(src: Vec<$U>) -> Vec<$T> {
+                let mut dst = alloc::vec![<$T>::default(); src.len()];
+                Self::from_slice(&src, &mut dst);
+                dst
+            }
+        }
+    };
+}
+
+impl_primitive_from_slice!(u8 => char, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f64);
+impl_primitive_from_slice!(i8 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from_slice!(u16 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from_slice!(i16 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f64);
+impl_primitive_from_slice!(u32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from_slice!(i32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from_slice!(u64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from_slice!(i64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from_slice!(usize => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from_slice!(isize => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from_slice!(u128 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from_slice!(i128 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from_slice!(f32 => u8, i8, u16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from_slice!(f64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from_slice!(char => char, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from_slice!(bool => bool, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_primitive_from_slice!(i16_to_f32: i16 => f32);
+impl_primitive_from_slice!(f32_to_i16: f32 => i16);
+impl_primitive_from_slice!(u8_to_f32: u8 => f32);
+
+
+#[test]
+fn convert_slice_matches_primitive_from() {
+    let ints: [i16; 19] = [0, 1, -1, 2, -2, 100, -100, 255, 256, i16::MIN, i16::MAX, 7, -7, 12345, -12345, 3, 4, 5, 6];
+    let mut floats = [0f32; 19];
+    convert_slice(&ints, &mut floats);
+    for (&a, &b) in ints.iter().zip(floats.iter()) {
+        assert_eq!(b, <f32 as PrimitiveFrom<i16>>::from(a));
+    }
+
+    let mut back = [0i16; 19];
+    convert_slice(&floats, &mut back);
+    assert_eq!(back, ints);
+
+    let mut wide = [0i64; 19];
+    convert_slice(&ints, &mut wide);
+    for (&a, &b) in ints.iter().zip(wide.iter()) {
+        assert_eq!(b, a as i64);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn convert_vec_matches_primitive_from() {
+    let v: Vec<f32> = convert_vec(std::vec![0i16, -1, i16::MIN, i16::MAX]);
+    assert_eq!(v, [0.0, -1.0, -32768.0, 32767.0]);
+    let v: Vec<u8> = convert_vec(std::vec![-1.5f64, 0.5, 255.5, 1e10, f64::NAN]);
+    assert_eq!(v, [0, 0, 255, 255, 0]);
+    let v: Vec<u32> = convert_vec(std::vec!['a', '\u{1F600}']);
+    assert_eq!(v, [0x61, 0x1F600]);
+}
+
+#[test]
+#[should_panic(expected = "different lengths")]
+fn convert_slice_length_mismatch() {
+    convert_slice(&[1u8, 2, 3], &mut [0f64; 2]);
+}
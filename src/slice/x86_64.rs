@@ -0,0 +1,164 @@
+use super::scalar;
+use core::arch::x86_64::*;
+
+#[inline]
+fn has_avx2() -> bool {
+    #[cfg(feature = "std")]
+    {
+        std::is_x86_feature_detected!("avx2")
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        cfg!(target_feature = "avx2")
+    }
+}
+
+macro_rules! dispatch {
+    ($name: ident, $avx2: ident, $sse2: ident, $U: ty => $T: ty) => {
+        #[inline]
+        pub(super) fn $name(src: &[$U], dst: &mut [$T]) {
+            assert_eq!(src.len(), dst.len(), "source and destination slices have different lengths");
+            // SSE2 is part of the x86_64 baseline.
+            if has_avx2() {
+                unsafe { $avx2(src, dst) }
+            } else {
+                unsafe { $sse2(src, dst) }
+            }
+        }
+    };
+}
+
+dispatch!(i16_to_f32, i16_to_f32_avx2, i16_to_f32_sse2, i16 => f32);
+dispatch!(f32_to_i16, f32_to_i16_avx2, f32_to_i16_sse2, f32 => i16);
+dispatch!(u8_to_f32, u8_to_f32_avx2, u8_to_f32_sse2, u8 => f32);
+
+#[target_feature(enable = "sse2")]
+unsafe fn i16_to_f32_sse2(src: &[i16], dst: &mut [f32]) {
+    let n = src.len() / 8 * 8;
+    for (s, d) in src[..n].chunks_exact(8).zip(dst[..n].chunks_exact_mut(8)) {
+        let x = _mm_loadu_si128(s.as_ptr() as *const __m128i);
+        // Interleaving with itself and shifting back sign extends to i32.
+        let lo = _mm_srai_epi32(_mm_unpacklo_epi16(x, x), 16);
+        let hi = _mm_srai_epi32(_mm_unpackhi_epi16(x, x), 16);
+        _mm_storeu_ps(d.as_mut_ptr(), _mm_cvtepi32_ps(lo));
+        _mm_storeu_ps(d.as_mut_ptr().add(4), _mm_cvtepi32_ps(hi));
+    }
+    scalar(&src[n..], &mut dst[n..]);
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn i16_to_f32_avx2(src: &[i16], dst: &mut [f32]) {
+    let n = src.len() / 8 * 8;
+    for (s, d) in src[..n].chunks_exact(8).zip(dst[..n].chunks_exact_mut(8)) {
+        let x = _mm256_cvtepi16_epi32(_mm_loadu_si128(s.as_ptr() as *const __m128i));
+        _mm256_storeu_ps(d.as_mut_ptr(), _mm256_cvtepi32_ps(x));
+    }
+    scalar(&src[n..], &mut dst[n..]);
+}
+
+// `as` maps NaN to 0 and saturates, while `cvttps` gives `i32::MIN` for
+// both, so NaN is zeroed and the input clamped to the range of `i16` first.
+#[target_feature(enable = "sse2")]
+unsafe fn f32_to_i32_clamped_sse2(x: __m128) -> __m128i {
+    let x = _mm_and_ps(x, _mm_cmpord_ps(x, x));
+    let x = _mm_min_ps(_mm_max_ps(x, _mm_set1_ps(-32768.0)), _mm_set1_ps(32767.0));
+    _mm_cvttps_epi32(x)
+}
+
+#[target_feature(enable = "sse2")]
+unsafe fn f32_to_i16_sse2(src: &[f32], dst: &mut [i16]) {
+    let n = src.len() / 8 * 8;
+    for (s, d) in src[..n].chunks_exact(8).zip(dst[..n].chunks_exact_mut(8)) {
+        let lo = f32_to_i32_clamped_sse2(_mm_loadu_ps(s.as_ptr()));
+        let hi = f32_to_i32_clamped_sse2(_mm_loadu_ps(s.as_ptr().add(4)));
+        _mm_storeu_si128(d.as_mut_ptr() as *mut __m128i, _mm_packs_epi32(lo, hi));
+    }
+    scalar(&src[n..], &mut dst[n..]);
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn f32_to_i16_avx2(src: &[f32], dst: &mut [i16]) {
+    let n = src.len() / 8 * 8;
+    for (s, d) in src[..n].chunks_exact(8).zip(dst[..n].chunks_exact_mut(8)) {
+        let x = _mm256_loadu_ps(s.as_ptr());
+        let x = _mm256_and_ps(x, _mm256_cmp_ps::<_CMP_ORD_Q>(x, x));
+        let x = _mm256_min_ps(_mm256_max_ps(x, _mm256_set1_ps(-32768.0)), _mm256_set1_ps(32767.0));
+        let x = _mm256_cvttps_epi32(x);
+        let packed = _mm_packs_epi32(_mm256_castsi256_si128(x), _mm256_extracti128_si256::<1>(x));
+        _mm_storeu_si128(d.as_mut_ptr() as *mut __m128i, packed);
+    }
+    scalar(&src[n..], &mut dst[n..]);
+}
+
+#[target_feature(enable = "sse2")]
+unsafe fn u8_to_f32_sse2(src: &[u8], dst: &mut [f32]) {
+    let n = src.len() / 16 * 16;
+    let zero = _mm_setzero_si128();
+    for (s, d) in src[..n].chunks_exact(16).zip(dst[..n].chunks_exact_mut(16)) {
+        let x = _mm_loadu_si128(s.as_ptr() as *const __m128i);
+        let halves = [_mm_unpacklo_epi8(x, zero), _mm_unpackhi_epi8(x, zero)];
+        for (i, &h) in halves.iter().enumerate() {
+            _mm_storeu_ps(d.as_mut_ptr().add(i * 8), _mm_cvtepi32_ps(_mm_unpacklo_epi16(h, zero)));
+            _mm_storeu_ps(d.as_mut_ptr().add(i * 8 + 4), _mm_cvtepi32_ps(_mm_unpackhi_epi16(h, zero)));
+        }
+    }
+    scalar(&src[n..], &mut dst[n..]);
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn u8_to_f32_avx2(src: &[u8], dst: &mut [f32]) {
+    let n = src.len() / 8 * 8;
+    for (s, d) in src[..n].chunks_exact(8).zip(dst[..n].chunks_exact_mut(8)) {
+        let x = _mm256_cvtepu8_epi32(_mm_loadl_epi64(s.as_ptr() as *const __m128i));
+        _mm256_storeu_ps(d.as_mut_ptr(), _mm256_cvtepi32_ps(x));
+    }
+    scalar(&src[n..], &mut dst[n..]);
+}
+
+
+#[test]
+fn simd_matches_scalar() {
+    let avx2 = std::is_x86_feature_detected!("avx2");
+
+    let ints: std::vec::Vec<i16> = (0..1000).map(|i: i32| (i * 65 - 32768) as i16).chain([i16::MIN, i16::MAX, 0, -1]).collect();
+    let floats: std::vec::Vec<f32> = (0..1000)
+        .map(|i| (i as f32 - 500.0) * 71.3)
+        .chain([f32::NAN, f32::INFINITY, f32::NEG_INFINITY, -0.0, 32767.9, -32768.9, 1e20, -1e20, 0.5, -0.5])
+        .collect();
+    let bytes: std::vec::Vec<u8> = (0..ints.len()).map(|i| i as u8).collect();
+
+    for len in [0, 1, 7, 8, 9, 15, 16, 17, 31, 100, ints.len()] {
+        let mut expected = std::vec![0f32; len];
+        let mut actual = std::vec![0f32; len];
+        scalar(&ints[..len], &mut expected);
+        unsafe { i16_to_f32_sse2(&ints[..len], &mut actual) };
+        assert_eq!(actual, expected);
+        if avx2 {
+            unsafe { i16_to_f32_avx2(&ints[..len], &mut actual) };
+            assert_eq!(actual, expected);
+        }
+
+        let mut expected = std::vec![0f32; len];
+        let mut actual = std::vec![0f32; len];
+        scalar(&bytes[..len], &mut expected);
+        unsafe { u8_to_f32_sse2(&bytes[..len], &mut actual) };
+        assert_eq!(actual, expected);
+        if avx2 {
+            unsafe { u8_to_f32_avx2(&bytes[..len], &mut actual) };
+            assert_eq!(actual, expected);
+        }
+    }
+
+    for len in [0, 1, 7, 8, 9, 15, 16, 17, 100, floats.len()] {
+        let src = &floats[floats.len() - len..];
+        let mut expected = std::vec![0i16; len];
+        let mut actual = std::vec![0i16; len];
+        scalar(src, &mut expected);
+        unsafe { f32_to_i16_sse2(src, &mut actual) };
+        assert_eq!(actual, expected);
+        if avx2 {
+            unsafe { f32_to_i16_avx2(src, &mut actual) };
+            assert_eq!(actual, expected);
+        }
+    }
+}
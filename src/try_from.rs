@@ -0,0 +1,321 @@
+use core::convert::TryFrom;
+use core::fmt;
+
+/// The reason a [`TryPrimitiveFrom`] conversion failed, along with the
+/// source value and the name of the target type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TryPrimitiveFromError<T> {
+    /// The value is greater than the maximum of the target type.
+    Overflow { value: T, target: &'static str },
+    /// The value is less than the minimum of the target type.
+    Underflow { value: T, target: &'static str },
+    /// The value is a float NaN and the target is an integer.
+    NotANumber { value: T, target: &'static str },
+    /// The value is a float infinity and the target is an integer.
+    Infinite { value: T, target: &'static str },
+    /// The value is not a valid unicode scalar value.
+    InvalidChar { value: T, target: &'static str },
+    /// The value cannot be represented exactly in the target type. Only
+    /// returned by [`ExactPrimitiveFrom`](crate::ExactPrimitiveFrom).
+    PrecisionLoss { value: T, target: &'static str },
+    /// The value converts to zero and the target is a `NonZero` integer.
+    Zero { value: T, target: &'static str },
+}
+
+impl<T> TryPrimitiveFromError<T> {
+    /// The value that failed to convert.
+    pub fn value(&self) -> &T {
+        match self {
+            TryPrimitiveFromError::Overflow { value, .. }
+            | TryPrimitiveFromError::Underflow { value, .. }
+            | TryPrimitiveFromError::NotANumber { value, .. }
+            | TryPrimitiveFromError::Infinite { value, .. }
+            | TryPrimitiveFromError::InvalidChar { value, .. }
+            | TryPrimitiveFromError::PrecisionLoss { value, .. }
+            | TryPrimitiveFromError::Zero { value, .. } => value,
+        }
+    }
+
+    /// The name of the type the value failed to convert to.
+    pub fn target(&self) -> &'static str {
+        match self {
+            TryPrimitiveFromError::Overflow { target, .. }
+            | TryPrimitiveFromError::Underflow { target, .. }
+            | TryPrimitiveFromError::NotANumber { target, .. }
+            | TryPrimitiveFromError::Infinite { target, .. }
+            | TryPrimitiveFromError::InvalidChar { target, .. }
+            | TryPrimitiveFromError::PrecisionLoss { target, .. }
+            | TryPrimitiveFromError::Zero { target, .. } => target,
+        }
+    }
+
+    /// The same error for another value and target, used when a conversion
+    /// goes through an intermediate type.
+    pub(crate) fn replace<V>(self, value: V, target: &'static str) -> TryPrimitiveFromError<V> {
+        match self {
+            TryPrimitiveFromError::Overflow { .. } => TryPrimitiveFromError::Overflow { value, target },
+            TryPrimitiveFromError::Underflow { .. } => TryPrimitiveFromError::Underflow { value, target },
+            TryPrimitiveFromError::NotANumber { .. } => TryPrimitiveFromError::NotANumber { value, target },
+            TryPrimitiveFromError::Infinite { .. } => TryPrimitiveFromError::Infinite { value, target },
+            TryPrimitiveFromError::InvalidChar { .. } => TryPrimitiveFromError::InvalidChar { value, target },
+            TryPrimitiveFromError::PrecisionLoss { .. } => TryPrimitiveFromError::PrecisionLoss { value, target },
+            TryPrimitiveFromError::Zero { .. } => TryPrimitiveFromError::Zero { value, target },
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for TryPrimitiveFromError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TryPrimitiveFromError::Overflow { value, target } => {
+                write!(f, "{} is too large to convert to {}", value, target)
+            }
+            TryPrimitiveFromError::Underflow { value, target } => {
+                write!(f, "{} is too small to convert to {}", value, target)
+            }
+            TryPrimitiveFromError::NotANumber { target, .. } => {
+                write!(f, "NaN cannot be converted to {}", target)
+            }
+            TryPrimitiveFromError::Infinite { value, target } => {
+                write!(f, "{} cannot be converted to {}", value, target)
+            }
+            TryPrimitiveFromError::InvalidChar { value, target } => {
+                write!(f, "{} is not a valid {}", value, target)
+            }
+            TryPrimitiveFromError::PrecisionLoss { value, target } => {
+                write!(f, "{} cannot be represented exactly as {}", value, target)
+            }
+            TryPrimitiveFromError::Zero { value, target } => {
+                write!(f, "{} converts to zero, which is not a valid {}", value, target)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug + fmt::Display> std::error::Error for TryPrimitiveFromError<T> {}
+
+/// A fallible version of [`PrimitiveFrom`](crate::PrimitiveFrom) that
+/// reports why a conversion failed.
+///
+/// It succeeds and fails on exactly the same values as
+/// [`CheckedPrimitiveFrom`](crate::CheckedPrimitiveFrom), but returns a
+/// [`TryPrimitiveFromError`] instead of `None`.
+///
+/// # Examples
+///
+/// ```
+/// # use primitive_from::{TryPrimitiveFrom, TryPrimitiveFromError};
+/// assert_eq!(<u8 as TryPrimitiveFrom<i32>>::try_from(200), Ok(200));
+///
+/// let err = <u8 as TryPrimitiveFrom<i32>>::try_from(-5).unwrap_err();
+/// assert_eq!(err, TryPrimitiveFromError::Underflow { value: -5, target: "u8" });
+/// assert_eq!(err.to_string(), "-5 is too small to convert to u8");
+///
+/// let err = <u16 as TryPrimitiveFrom<f64>>::try_from(f64::NAN).unwrap_err();
+/// assert_eq!(err.to_string(), "NaN cannot be converted to u16");
+/// ```
+pub trait TryPrimitiveFrom<T>: Sized
+{
+    fn try_from(_:T) -> Result<Self, TryPrimitiveFromError<T>>;
+}
+
+macro_rules! impl_try_primitive_from {
+    (int $U: ty => $( $T: ty ),* ) => {
+        $(
+        impl TryPrimitiveFrom<$U> for $T {
+            #[inline] fn try_from(a:$U) -> Result<$T, TryPrimitiveFromError<$U>> {
+                <$T as TryFrom<$U>>::try_from(a).map_err(|_| {
+                    let target = stringify!($T);
+                    if a > <$U>::default() {
+                        TryPrimitiveFromError::Overflow { value: a, target }
+                    } else {
+                        TryPrimitiveFromError::Underflow { value: a, target }
+                    }
+                })
+            }
+        }
+        )*
+    };
+    (int_to_float $U: ty => $( $T: ty ),* ) => {
+        $(
+        impl TryPrimitiveFrom<$U> for $T {
+            #[inline] fn try_from(a:$U) -> Result<$T, TryPrimitiveFromError<$U>> {
+                let b = a as $T;
+                if b.is_infinite() {
+                    Err(TryPrimitiveFromError::Overflow { value: a, target: stringify!($T) })
+                } else {
+                    Ok(b)
+                }
+            }
+        }
+        )*
+    };
+    (float_to_int $U: ty => $( $T: ty ),* ) => {
+        $(
+        impl TryPrimitiveFrom<$U> for $T {
+            #[inline] fn try_from(a:$U) -> Result<$T, TryPrimitiveFromError<$U>> {
+                let target = stringify!($T);
+                let min = <$T>::MIN as $U;
+                if a.is_nan() {
+                    Err(TryPrimitiveFromError::NotANumber { value: a, target })
+                } else if a.is_infinite() {
+                    Err(TryPrimitiveFromError::Infinite { value: a, target })
+                } else if a < min && a <= min - 1.0 {
+                    Err(TryPrimitiveFromError::Underflow { value: a, target })
+                } else if a >= <$T>::MAX as $U + 1.0 {
+                    Err(TryPrimitiveFromError::Overflow { value: a, target })
+                } else {
+                    Ok(a as $T)
+                }
+            }
+        }
+        )*
+    };
+    (float $U: ty => $( $T: ty ),* ) => {
+        $(
+        impl TryPrimitiveFrom<$U> for $T {
+            #[inline] fn try_from(a:$U) -> Result<$T, TryPrimitiveFromError<$U>> {
+                let b = a as $T;
+                let target = stringify!($T);
+                if b.is_infinite() && a.is_finite() {
+                    if a > 0.0 {
+                        Err(TryPrimitiveFromError::Overflow { value: a, target })
+                    } else {
+                        Err(TryPrimitiveFromError::Underflow { value: a, target })
+                    }
+                } else {
+                    Ok(b)
+                }
+            }
+        }
+        )*
+    };
+    (char => $( $T: ty ),* ) => {
+        $(
+        impl TryPrimitiveFrom<char> for $T {
+            #[inline] fn try_from(a:char) -> Result<$T, TryPrimitiveFromError<char>> {
+                <$T as TryFrom<u32>>::try_from(a as u32)
+                    .map_err(|_| TryPrimitiveFromError::Overflow { value: a, target: stringify!($T) })
+            }
+        }
+        )*
+    };
+    (bool => $( $T: ty ),* ) => {
+        $(
+        impl TryPrimitiveFrom<bool> for $T {
+            #[inline] fn try_from(a:bool) -> Result<$T, TryPrimitiveFromError<bool>> { Ok(crate::PrimitiveFrom::from(a)) }
+        }
+        )*
+    };
+    (int_to_char $( $U: ty ),* ) => {
+        $(
+        impl TryPrimitiveFrom<$U> for char {
+            #[inline] fn try_from(a:$U) -> Result<char, TryPrimitiveFromError<$U>> {
+                <u32 as TryFrom<$U>>::try_from(a)
+                    .ok()
+                    .and_then(core::char::from_u32)
+                    .ok_or(TryPrimitiveFromError::InvalidChar { value: a, target: "char" })
+            }
+        }
+        )*
+    };
+    (int_to_bool $( $U: ty ),* ) => {
+        $(
+        impl TryPrimitiveFrom<$U> for bool {
+            #[inline] fn try_from(a:$U) -> Result<bool, TryPrimitiveFromError<$U>> {
+                match a {
+                    0 => Ok(false),
+                    1 => Ok(true),
+                    _ if a > 1 => Err(TryPrimitiveFromError::Overflow { value: a, target: "bool" }),
+                    _ => Err(TryPrimitiveFromError::Underflow { value: a, target: "bool" }),
+                }
+            }
+        }
+        )*
+    };
+}
+
+impl_try_primitive_from!(int u8 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_try_primitive_from!(int i8 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_try_primitive_from!(int u16 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_try_primitive_from!(int i16 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_try_primitive_from!(int u32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_try_primitive_from!(int i32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_try_primitive_from!(int u64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_try_primitive_from!(int i64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_try_primitive_from!(int usize => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_try_primitive_from!(int isize => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_try_primitive_from!(int u128 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_try_primitive_from!(int i128 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_try_primitive_from!(int_to_float u8 => f32, f64);
+impl_try_primitive_from!(int_to_float i8 => f32, f64);
+impl_try_primitive_from!(int_to_float u16 => f32, f64);
+impl_try_primitive_from!(int_to_float i16 => f32, f64);
+impl_try_primitive_from!(int_to_float u32 => f32, f64);
+impl_try_primitive_from!(int_to_float i32 => f32, f64);
+impl_try_primitive_from!(int_to_float u64 => f32, f64);
+impl_try_primitive_from!(int_to_float i64 => f32, f64);
+impl_try_primitive_from!(int_to_float usize => f32, f64);
+impl_try_primitive_from!(int_to_float isize => f32, f64);
+impl_try_primitive_from!(int_to_float u128 => f32, f64);
+impl_try_primitive_from!(int_to_float i128 => f32, f64);
+impl_try_primitive_from!(float_to_int f32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_try_primitive_from!(float_to_int f64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_try_primitive_from!(float f32 => f32, f64);
+impl_try_primitive_from!(float f64 => f32, f64);
+impl_try_primitive_from!(char => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_try_primitive_from!(bool => bool, u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128, f32, f64);
+impl_try_primitive_from!(int_to_char i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_try_primitive_from!(int_to_bool u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+
+impl TryPrimitiveFrom<u8> for char {
+    #[inline] fn try_from(a:u8) -> Result<char, TryPrimitiveFromError<u8>> { Ok(a as char) }
+}
+
+impl TryPrimitiveFrom<char> for char {
+    #[inline] fn try_from(a:char) -> Result<char, TryPrimitiveFromError<char>> { Ok(a) }
+}
+
+impl TryPrimitiveFrom<char> for f32 {
+    #[inline] fn try_from(a:char) -> Result<f32, TryPrimitiveFromError<char>> { Ok(crate::PrimitiveFrom::from(a)) }
+}
+
+impl TryPrimitiveFrom<char> for f64 {
+    #[inline] fn try_from(a:char) -> Result<f64, TryPrimitiveFromError<char>> { Ok(crate::PrimitiveFrom::from(a)) }
+}
+
+
+#[test]
+fn try_primitive_from() {
+    use TryPrimitiveFromError::*;
+
+    assert_eq!(<u8 as TryPrimitiveFrom<i16>>::try_from(768), Err(Overflow { value: 768, target: "u8" }));
+    assert_eq!(<u8 as TryPrimitiveFrom<i16>>::try_from(-1), Err(Underflow { value: -1, target: "u8" }));
+    assert_eq!(<i64 as TryPrimitiveFrom<u128>>::try_from(5), Ok(5));
+
+    assert_eq!(<i8 as TryPrimitiveFrom<f32>>::try_from(-128.5), Ok(-128));
+    assert_eq!(<i8 as TryPrimitiveFrom<f32>>::try_from(-129.0), Err(Underflow { value: -129.0, target: "i8" }));
+    assert_eq!(<i8 as TryPrimitiveFrom<f32>>::try_from(128.0), Err(Overflow { value: 128.0, target: "i8" }));
+    assert_eq!(<u64 as TryPrimitiveFrom<f64>>::try_from(f64::NEG_INFINITY), Err(Infinite { value: f64::NEG_INFINITY, target: "u64" }));
+    assert!(matches!(<u64 as TryPrimitiveFrom<f64>>::try_from(f64::NAN), Err(NotANumber { target: "u64", .. })));
+
+    assert_eq!(<f32 as TryPrimitiveFrom<u128>>::try_from(u128::MAX), Err(Overflow { value: u128::MAX, target: "f32" }));
+    assert_eq!(<f32 as TryPrimitiveFrom<f64>>::try_from(-1e300), Err(Underflow { value: -1e300, target: "f32" }));
+
+    assert_eq!(<char as TryPrimitiveFrom<u32>>::try_from(0xD800), Err(InvalidChar { value: 0xD800, target: "char" }));
+    assert_eq!(<u8 as TryPrimitiveFrom<char>>::try_from('\u{100}'), Err(Overflow { value: '\u{100}', target: "u8" }));
+    assert_eq!(<char as TryPrimitiveFrom<i16>>::try_from(-1), Err(InvalidChar { value: -1, target: "char" }));
+    assert_eq!(<char as TryPrimitiveFrom<u64>>::try_from(0x41), Ok('A'));
+    assert_eq!(<bool as TryPrimitiveFrom<u8>>::try_from(1), Ok(true));
+    assert_eq!(<bool as TryPrimitiveFrom<u8>>::try_from(2), Err(Overflow { value: 2, target: "bool" }));
+    assert_eq!(<bool as TryPrimitiveFrom<i8>>::try_from(-1), Err(Underflow { value: -1, target: "bool" }));
+    assert_eq!(<f32 as TryPrimitiveFrom<char>>::try_from('A'), Ok(65.0));
+
+    let err = <i8 as TryPrimitiveFrom<f64>>::try_from(f64::INFINITY).unwrap_err();
+    assert_eq!(*err.value(), f64::INFINITY);
+    assert_eq!(err.target(), "i8");
+    assert_eq!(err.to_string(), "inf cannot be converted to i8");
+    assert_eq!(Overflow { value: 300, target: "u8" }.to_string(), "300 is too large to convert to u8");
+    assert_eq!(InvalidChar { value: 0xD800, target: "char" }.to_string(), "55296 is not a valid char");
+}
@@ -0,0 +1,69 @@
+/// A wrapping version of [`PrimitiveFrom`](crate::PrimitiveFrom), which
+/// makes it explicit that truncation is intended.
+///
+/// The conversion is modular: the result is the source value modulo
+/// `2^N`, where `N` is the bit width of the target, reinterpreted in two's
+/// complement for signed targets. A `bool` converts as 0 or 1, and a `char`
+/// converts through its code point.
+///
+/// Float sources are deliberately not implemented, since there is no
+/// sensible modular interpretation of a float. Use
+/// [`SaturatingPrimitiveFrom`](crate::SaturatingPrimitiveFrom) or
+/// [`CheckedPrimitiveFrom`](crate::CheckedPrimitiveFrom) for those.
+///
+/// ```compile_fail
+/// # use primitive_from::WrappingPrimitiveFrom;
+/// let x = <u8 as WrappingPrimitiveFrom<f32>>::wrapping_from(300.0);
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// # use primitive_from::WrappingPrimitiveFrom;
+/// assert_eq!(<u8 as WrappingPrimitiveFrom<u16>>::wrapping_from(300), 44);
+/// assert_eq!(<i8 as WrappingPrimitiveFrom<u8>>::wrapping_from(200), -56);
+/// assert_eq!(<u32 as WrappingPrimitiveFrom<i8>>::wrapping_from(-1), u32::MAX);
+/// assert_eq!(<u8 as WrappingPrimitiveFrom<char>>::wrapping_from('\u{1F600}'), 0x00);
+/// ```
+pub trait WrappingPrimitiveFrom<T>
+{
+    fn wrapping_from(_:T) -> Self;
+}
+
+macro_rules! impl_wrapping_primitive_from {
+    ($U: ty => $( $T: ty ),* ) => {
+        $(
+        impl WrappingPrimitiveFrom<$U> for $T {
+            #[inline] fn wrapping_from(a:$U) -> $T { a as $T }
+        }
+        )*
+    };
+}
+
+impl_wrapping_primitive_from!(u8 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_wrapping_primitive_from!(i8 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_wrapping_primitive_from!(u16 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_wrapping_primitive_from!(i16 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_wrapping_primitive_from!(u32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_wrapping_primitive_from!(i32 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_wrapping_primitive_from!(u64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_wrapping_primitive_from!(i64 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_wrapping_primitive_from!(usize => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_wrapping_primitive_from!(isize => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_wrapping_primitive_from!(u128 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_wrapping_primitive_from!(i128 => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_wrapping_primitive_from!(char => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+impl_wrapping_primitive_from!(bool => u8, i8, u16, i16, u32, i32, u64, isize, usize, i64, u128, i128);
+
+
+#[test]
+fn wrapping_primitive_from() {
+    assert_eq!(<u8 as WrappingPrimitiveFrom<i16>>::wrapping_from(768), 0);
+    assert_eq!(<u8 as WrappingPrimitiveFrom<i16>>::wrapping_from(-1), u8::MAX);
+    assert_eq!(<i16 as WrappingPrimitiveFrom<u32>>::wrapping_from(0x1_8000), i16::MIN);
+    assert_eq!(<u64 as WrappingPrimitiveFrom<u128>>::wrapping_from((1 << 64) + 3), 3);
+    assert_eq!(<i128 as WrappingPrimitiveFrom<u128>>::wrapping_from(u128::MAX), -1);
+    assert_eq!(<u128 as WrappingPrimitiveFrom<i64>>::wrapping_from(-2), u128::MAX - 1);
+    assert_eq!(<i8 as WrappingPrimitiveFrom<char>>::wrapping_from('\u{FF}'), -1);
+    assert_eq!(<u16 as WrappingPrimitiveFrom<bool>>::wrapping_from(true), 1);
+}